@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Life-like rule: a dead cell comes alive when its number of live
+/// neighbours is in `birth`, a live cell stays alive when it is in
+/// `survival`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    pub fn new(
+        birth: impl IntoIterator<Item = usize>,
+        survival: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        Rule {
+            birth: birth.into_iter().collect(),
+            survival: survival.into_iter().collect(),
+        }
+    }
+
+    pub fn next(&self, alive: bool, live_neighbours: usize) -> bool {
+        if alive {
+            self.survival.contains(&live_neighbours)
+        } else {
+            self.birth.contains(&live_neighbours)
+        }
+    }
+}
+
+pub trait Automaton {
+    type Cell;
+
+    /// Advances one generation and returns whether any cell changed.
+    fn step(&mut self) -> bool;
+
+    /// Number of live cells.
+    fn population(&self) -> usize;
+
+    fn is_alive(&self, cell: &Self::Cell) -> bool;
+
+    fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Steps until nothing changes and returns the number of generations
+    /// that changed something.
+    fn run_until_stable(&mut self) -> usize {
+        let mut generations = 0;
+        while self.step() {
+            generations += 1;
+        }
+        generations
+    }
+}
+
+/// Automaton on an unbounded space, storing only the live cells.
+#[derive(Clone)]
+pub struct Sparse<C, N> {
+    live: HashSet<C>,
+    neighbourhood: N,
+    weight: fn(&C) -> usize,
+    rule: Rule,
+}
+
+impl<C, N, I> Sparse<C, N>
+where
+    C: Copy + Eq + Hash,
+    N: Fn(&C) -> I,
+    I: IntoIterator<Item = C>,
+{
+    pub fn new(live: impl IntoIterator<Item = C>, neighbourhood: N, rule: Rule) -> Self {
+        Self::weighted(live, neighbourhood, |_| 1, rule)
+    }
+
+    /// Automaton where every stored cell stands for `weight` cells of a
+    /// space folded onto itself, e.g. by symmetry. `neighbourhood` gives
+    /// the folded neighbours of a cell, the same one as often as it is
+    /// reached.
+    pub fn weighted(
+        live: impl IntoIterator<Item = C>,
+        neighbourhood: N,
+        weight: fn(&C) -> usize,
+        rule: Rule,
+    ) -> Self {
+        Sparse {
+            live: live.into_iter().collect(),
+            neighbourhood,
+            weight,
+            rule,
+        }
+    }
+
+    pub fn live(&self) -> &HashSet<C> {
+        &self.live
+    }
+
+    pub fn into_live(self) -> HashSet<C> {
+        self.live
+    }
+}
+
+impl<C, N, I> Automaton for Sparse<C, N>
+where
+    C: Copy + Eq + Hash,
+    N: Fn(&C) -> I,
+    I: IntoIterator<Item = C>,
+{
+    type Cell = C;
+
+    fn step(&mut self) -> bool {
+        // count live neighbours from the live cells outwards, so only cells
+        // next to a live one are ever looked at
+        let mut counts: HashMap<C, usize> = HashMap::new();
+        for cell in &self.live {
+            let weight = (self.weight)(cell);
+            for neighbour in (self.neighbourhood)(cell) {
+                *counts.entry(neighbour).or_insert(0) += weight;
+            }
+        }
+
+        // a folded cell is reached once from each cell it stands for
+        let live_neighbours = |cell: &C, count: usize| count / (self.weight)(cell);
+
+        let mut next: HashSet<C> = counts
+            .iter()
+            .filter(|(cell, &count)| {
+                self.rule
+                    .next(self.live.contains(cell), live_neighbours(cell, count))
+            })
+            .map(|(&cell, _)| cell)
+            .collect();
+        // live cells without live neighbours are not in the counts
+        next.extend(
+            self.live
+                .iter()
+                .filter(|cell| !counts.contains_key(cell) && self.rule.next(true, 0)),
+        );
+
+        let changed = next != self.live;
+        self.live = next;
+        changed
+    }
+
+    /// Number of live cells, counting every cell a folded one stands for.
+    fn population(&self) -> usize {
+        self.live.iter().map(|cell| (self.weight)(cell)).sum()
+    }
+
+    fn is_alive(&self, cell: &C) -> bool {
+        self.live.contains(cell)
+    }
+}
+
+/// Automaton on a fixed set of cells, with the neighbours of every cell
+/// looked up once up front.
+pub struct Dense<C> {
+    cells: Vec<C>,
+    index: HashMap<C, usize>,
+    neighbours: Vec<Vec<usize>>,
+    alive: Vec<bool>,
+    rule: Rule,
+}
+
+impl<C> Dense<C>
+where
+    C: Copy + Eq + Hash,
+{
+    /// Neighbours returned by `neighbourhood` that are not among `cells`
+    /// are never alive.
+    pub fn new(
+        cells: impl IntoIterator<Item = (C, bool)>,
+        neighbourhood: impl Fn(&C) -> Vec<C>,
+        rule: Rule,
+    ) -> Self {
+        let (cells, alive): (Vec<C>, Vec<bool>) = cells.into_iter().unzip();
+        let index: HashMap<C, usize> = cells.iter().enumerate().map(|(i, &c)| (c, i)).collect();
+
+        let neighbours = cells
+            .iter()
+            .map(|cell| {
+                neighbourhood(cell)
+                    .iter()
+                    .filter_map(|n| index.get(n).copied())
+                    .collect()
+            })
+            .collect();
+
+        Dense {
+            cells,
+            index,
+            neighbours,
+            alive,
+            rule,
+        }
+    }
+
+    /// Every cell with whether it is alive.
+    pub fn iter(&self) -> impl Iterator<Item = (&C, bool)> {
+        self.cells.iter().zip(self.alive.iter().copied())
+    }
+}
+
+impl<C> Automaton for Dense<C>
+where
+    C: Copy + Eq + Hash,
+{
+    type Cell = C;
+
+    fn step(&mut self) -> bool {
+        let next: Vec<bool> = self
+            .neighbours
+            .iter()
+            .zip(self.alive.iter())
+            .map(|(neighbours, &alive)| {
+                let live_neighbours = neighbours.iter().filter(|&&n| self.alive[n]).count();
+                self.rule.next(alive, live_neighbours)
+            })
+            .collect();
+
+        let changed = next != self.alive;
+        self.alive = next;
+        changed
+    }
+
+    fn population(&self) -> usize {
+        self.alive.iter().filter(|&&a| a).count()
+    }
+
+    fn is_alive(&self, cell: &C) -> bool {
+        self.index.get(cell).is_some_and(|&i| self.alive[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square_neighbours(&(x, y): &(i64, i64)) -> Vec<(i64, i64)> {
+        let mut neighbours = Vec::new();
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx != 0 || dy != 0 {
+                    neighbours.push((x + dx, y + dy));
+                }
+            }
+        }
+        neighbours
+    }
+
+    fn life() -> Rule {
+        Rule::new(vec![3], vec![2, 3])
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule = life();
+
+        assert!(rule.next(false, 3));
+        assert!(!rule.next(false, 2));
+        assert!(rule.next(true, 2));
+        assert!(!rule.next(true, 4));
+    }
+
+    #[test]
+    fn test_sparse_blinker() {
+        let mut blinker = Sparse::new(vec![(0, -1), (0, 0), (0, 1)], square_neighbours, life());
+
+        assert!(blinker.step());
+        let mut live: Vec<(i64, i64)> = blinker.live().iter().copied().collect();
+        live.sort_unstable();
+        assert_eq!(live, vec![(-1, 0), (0, 0), (1, 0)]);
+
+        blinker.run(2);
+        assert!(blinker.is_alive(&(1, 0)));
+        assert!(!blinker.is_alive(&(0, 1)));
+        assert_eq!(blinker.population(), 3);
+    }
+
+    #[test]
+    fn test_sparse_isolated_cells() {
+        // cells without any live neighbours never show up in the counts
+        let mut lonely = Sparse::new(
+            vec![(0, 0), (5, 5)],
+            square_neighbours,
+            Rule::new(vec![], vec![0]),
+        );
+        assert!(!lonely.step());
+        assert_eq!(lonely.population(), 2);
+
+        let mut dying = Sparse::new(vec![(0, 0), (5, 5)], square_neighbours, life());
+        assert!(dying.step());
+        assert_eq!(dying.population(), 0);
+    }
+
+    #[test]
+    fn test_sparse_weighted() {
+        // a blinker across the x axis folded onto y >= 0
+        fn folded(cell: &(i64, i64)) -> Vec<(i64, i64)> {
+            square_neighbours(cell)
+                .into_iter()
+                .map(|(x, y)| (x, y.abs()))
+                .collect()
+        }
+        fn weight(&(_, y): &(i64, i64)) -> usize {
+            if y == 0 {
+                1
+            } else {
+                2
+            }
+        }
+
+        let mut blinker = Sparse::weighted(vec![(-1, 0), (0, 0), (1, 0)], folded, weight, life());
+        assert_eq!(blinker.population(), 3);
+
+        blinker.step();
+        let mut live: Vec<(i64, i64)> = blinker.live().iter().copied().collect();
+        live.sort_unstable();
+        assert_eq!(live, vec![(0, 0), (0, 1)]);
+        assert_eq!(blinker.population(), 3);
+    }
+
+    #[test]
+    fn test_dense_run_until_stable() {
+        // a 3x3 board where the centre row is alive
+        let cells: Vec<((i64, i64), bool)> = (0..3)
+            .flat_map(|y| (0..3).map(move |x| ((x, y), y == 1)))
+            .collect();
+        let mut board = Dense::new(cells, square_neighbours, Rule::new(vec![3], vec![2, 3]));
+
+        assert!(board.step());
+        assert!(board.is_alive(&(1, 0)));
+        assert!(!board.is_alive(&(0, 1)));
+
+        // a block stays put in the corner of a bounded board
+        let cells = vec![
+            ((0, 0), true),
+            ((1, 0), true),
+            ((0, 1), true),
+            ((1, 1), false),
+        ];
+        let mut block = Dense::new(cells, square_neighbours, life());
+        assert_eq!(block.run_until_stable(), 1);
+        assert_eq!(block.population(), 4);
+        assert!(block.iter().all(|(_, alive)| alive));
+    }
+}
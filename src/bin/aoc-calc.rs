@@ -0,0 +1,38 @@
+use adventofcode_2020::day18::calc::Calculator;
+use adventofcode_2020::day18::expr::Precedence;
+use std::env;
+use std::io;
+use std::process;
+
+const USAGE: &str = "usage:
+    aoc-calc [mode]         evaluate expressions read from stdin, one per line
+
+modes:
+    part1                   every operator on the same level, the default
+    part2                   addition and subtraction before multiplication
+    math                    multiplication before addition and subtraction
+
+type :help for the commands";
+
+fn run(args: &[String]) -> Result<(), String> {
+    let precedence = match args {
+        [] => Precedence::EQUAL,
+        [mode] => mode
+            .parse()
+            .map_err(|err| format!("invalid mode {:?}: {}\n\n{}", mode, err, USAGE))?,
+        _ => return Err(USAGE.to_owned()),
+    };
+
+    Calculator::new(precedence)
+        .run(io::stdin().lock(), &mut io::stdout())
+        .map_err(|err| err.to_string())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(err) = run(&args) {
+        eprintln!("{}", err);
+        process::exit(2);
+    }
+}
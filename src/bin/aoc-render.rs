@@ -0,0 +1,169 @@
+use adventofcode_2020::input;
+use adventofcode_2020::render;
+use adventofcode_2020::render::Animation;
+use adventofcode_2020::solution::Options;
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "usage:
+    aoc-render <day> ansi [input] [--name value]...
+                                   play every generation in the terminal
+    aoc-render <day> ppm|png <dir> [input] [--name value]...
+                                   write one image per generation into dir
+    aoc-render <day> gif <file> [input] [--name value]...
+                                   write every generation as an animated GIF
+    aoc-render 24 svg <file> [input] [--generations N]
+                                   write the floor after the last day as an SVG image
+    aoc-render 24 svg-days <dir> [input] [--generations N]
+                                   write one SVG image per day into dir
+
+days: 11, 17 and 24, input defaults to src/dayNN/input.txt
+
+settings:
+    --generations N                generations to run, day 11 runs until nobody moves
+    --scale N                      pixels per cell in images, 4 by default
+    --delay MS                     time each generation is shown, 100 by default
+    11 --neighbours adjacent|sight|N --tolerance N
+    17 --dimensions 3|4";
+
+const SCALE: usize = 4;
+const DELAY: u64 = 100;
+
+// splits the arguments into positional arguments and settings
+fn parse_args(args: &[String]) -> Result<(Vec<&str>, Options), String> {
+    let mut positional = Vec::new();
+    let mut options = Options::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--") {
+            Some(setting) => match setting.split_once('=') {
+                Some((name, value)) => options.set(name, value),
+                None => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for --{}", setting))?;
+                    options.set(setting, value.as_str());
+                }
+            },
+            None => positional.push(arg.as_str()),
+        }
+    }
+
+    Ok((positional, options))
+}
+
+fn input_name(day: u32, filename: Option<&str>) -> String {
+    match filename {
+        Some(f) => f.to_owned(),
+        None => format!("src/day{:02}/input.txt", day),
+    }
+}
+
+fn load(day: u32, filename: Option<&str>, options: &Options) -> Result<Animation, String> {
+    let filename = input_name(day, filename);
+    let mut input_file =
+        input::open_input(&filename).map_err(|err| format!("{}: {}", filename, err))?;
+    render::simulate(day, &mut input_file, options).map_err(|err| format!("{}: {}", filename, err))
+}
+
+fn load_svgs(filename: Option<&str>, options: &Options) -> Result<Vec<String>, String> {
+    let filename = input_name(24, filename);
+    let mut input_file =
+        input::open_input(&filename).map_err(|err| format!("{}: {}", filename, err))?;
+    render::floor_svgs(&mut input_file, options).map_err(|err| format!("{}: {}", filename, err))
+}
+
+fn write_frames(animation: &Animation, dir: &str, format: &str, scale: usize) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (generation, frame) in animation.frames.iter().enumerate() {
+        let path = Path::new(dir).join(format!("{:04}.{}", generation, format));
+        let mut out = BufWriter::new(File::create(path)?);
+        let frame = frame.scaled(scale);
+        match format {
+            "ppm" => render::write_ppm(&mut out, &frame, &animation.palette)?,
+            _ => render::write_png(&mut out, &frame, &animation.palette)?,
+        }
+    }
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (positional, options) = parse_args(args)?;
+    if positional.len() < 2 {
+        return Err(USAGE.to_owned());
+    }
+
+    let day: u32 = positional[0]
+        .parse()
+        .map_err(|_| format!("invalid day {:?}", positional[0]))?;
+    if !render::DAYS.contains(&day) {
+        return Err(format!("day {} has no simulation to draw", day));
+    }
+
+    let scale = options
+        .parse_or("scale", SCALE)
+        .map_err(|err| err.to_string())?;
+    if scale == 0 {
+        return Err("--scale \"0\": must be at least 1".to_owned());
+    }
+    let delay = options
+        .parse_or("delay", DELAY)
+        .map_err(|err| err.to_string())?;
+    let delay = Duration::from_millis(delay);
+    if let Some(name) = options
+        .names()
+        .find(|&name| name != "scale" && name != "delay" && !render::options(day).contains(&name))
+    {
+        return Err(format!("day {} does not take --{}", day, name));
+    }
+
+    let format = positional[1];
+    match (format, &positional[2..]) {
+        ("ansi", rest) if rest.len() <= 1 => {
+            let animation = load(day, rest.first().copied(), &options)?;
+            render::play_ansi(&mut io::stdout(), &animation, delay)
+        }
+        ("ppm", [dir, rest @ ..]) | ("png", [dir, rest @ ..]) if rest.len() <= 1 => {
+            let animation = load(day, rest.first().copied(), &options)?;
+            write_frames(&animation, dir, format, scale)
+        }
+        ("gif", [file, rest @ ..]) if rest.len() <= 1 => {
+            let mut animation = load(day, rest.first().copied(), &options)?;
+            for frame in animation.frames.iter_mut() {
+                *frame = frame.scaled(scale);
+            }
+            File::create(file)
+                .and_then(|f| render::write_gif(&mut BufWriter::new(f), &animation, delay))
+        }
+        ("svg", [file, rest @ ..]) if day == 24 && rest.len() <= 1 => {
+            let svgs = load_svgs(rest.first().copied(), &options)?;
+            fs::write(file, svgs.last().map_or("", |svg| svg.as_str()))
+        }
+        ("svg-days", [dir, rest @ ..]) if day == 24 && rest.len() <= 1 => {
+            let svgs = load_svgs(rest.first().copied(), &options)?;
+            fs::create_dir_all(dir).and_then(|_| {
+                svgs.iter().enumerate().try_for_each(|(day, svg)| {
+                    fs::write(Path::new(dir).join(format!("{:04}.svg", day)), svg)
+                })
+            })
+        }
+        _ => return Err(USAGE.to_owned()),
+    }
+    .map_err(|err| err.to_string())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(err) = run(&args) {
+        eprintln!("{}", err);
+        process::exit(2);
+    }
+}
@@ -0,0 +1,54 @@
+use adventofcode_2020::day08::debugger;
+use adventofcode_2020::day08::debugger::Debugger;
+use adventofcode_2020::day08::part1::read_input;
+use adventofcode_2020::day08::part1::Instruction;
+use adventofcode_2020::input;
+use std::env;
+use std::io;
+use std::process;
+
+const USAGE: &str = "usage:
+    aoc-vm disasm [input]   list the boot code with jump targets
+    aoc-vm dot [input]      print the control flow graph in Graphviz DOT format
+    aoc-vm debug [input]    step through the boot code, reading commands from stdin
+
+input defaults to src/day08/input.txt";
+
+const DEFAULT_INPUT: &str = "src/day08/input.txt";
+
+fn load_program(filename: Option<&String>) -> Result<Vec<Instruction>, String> {
+    let filename = filename.map(|f| f.as_str()).unwrap_or(DEFAULT_INPUT);
+    let input_file = input::open_input(filename).map_err(|err| format!("{}: {}", filename, err))?;
+    read_input(input_file).map_err(|err| format!("{}: {}", filename, err))
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    if args.is_empty() || args.len() > 2 {
+        return Err(USAGE.to_owned());
+    }
+
+    match args[0].as_str() {
+        "disasm" => println!("{}", debugger::disassemble(&load_program(args.get(1))?)),
+        "dot" => println!(
+            "{}",
+            debugger::control_flow_dot(&load_program(args.get(1))?)
+        ),
+        "debug" => {
+            let mut debugger = Debugger::new(load_program(args.get(1))?);
+            debugger
+                .run(io::stdin().lock(), &mut io::stdout())
+                .map_err(|err| err.to_string())?;
+        }
+        _ => return Err(USAGE.to_owned()),
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(err) = run(&args) {
+        eprintln!("{}", err);
+        process::exit(2);
+    }
+}
@@ -0,0 +1,39 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::io::Read;
+
+fn read_input(reader: impl Read) -> Result<Vec<i64>, InputError> {
+    input::read_parsed(reader)
+}
+
+fn find_sum(inputs: &[i64]) -> i64 {
+    for (i, a) in inputs.iter().enumerate() {
+        for b in inputs.iter().skip(i) {
+            if a + b == 2020 {
+                return a * b;
+            }
+        }
+    }
+    -1
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let inputs = read_input(reader)?;
+        Ok(find_sum(&inputs).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        let test_inputs: Vec<i64> = vec![1721, 979, 366, 299, 675, 1456];
+        assert_eq!(find_sum(&test_inputs), 514579);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use regex::Regex;
+use std::io::Read;
+
+struct Input {
+    min: usize,
+    max: usize,
+    letter: char,
+    password: String,
+}
+
+fn read_input(reader: impl Read) -> Result<Vec<Input>, InputError> {
+    let re = Regex::new(r"(\d+)-(\d+) (.): (.+)").unwrap();
+
+    let mut output = Vec::new();
+    for x in input::read_lines(reader)? {
+        let capture_iter = re.captures_iter(x.as_str());
+        for m in capture_iter {
+            let i = Input {
+                min: m[1].parse::<usize>().unwrap(),
+                max: m[2].parse::<usize>().unwrap(),
+                letter: m[3].chars().next().unwrap(),
+                password: m[4].to_owned(),
+            };
+
+            output.push(i);
+        }
+    }
+
+    Ok(output)
+}
+
+fn validate_inputs(inputs: &[Input]) -> i64 {
+    let mut valids = 0;
+    for i in inputs.iter() {
+        let count = i.password.chars().filter(|&c| c == i.letter).count();
+        if count >= i.min && count <= i.max {
+            valids += 1;
+        }
+    }
+    valids
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let inputs = read_input(reader)?;
+        Ok(validate_inputs(&inputs).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_inputs() {
+        let test_inputs: Vec<Input> = vec![
+            Input {
+                min: 1,
+                max: 3,
+                letter: 'a',
+                password: "abcde".to_owned(),
+            },
+            Input {
+                min: 1,
+                max: 3,
+                letter: 'b',
+                password: "cdefg".to_owned(),
+            },
+            Input {
+                min: 2,
+                max: 9,
+                letter: 'c',
+                password: "ccccccccc".to_owned(),
+            },
+        ];
+        assert_eq!(validate_inputs(&test_inputs), 2);
+    }
+}
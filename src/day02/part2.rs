@@ -1,9 +1,8 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use regex::Regex;
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
-use std::path::Path;
+use std::io::Read;
 
 struct Input {
     pos1: usize,
@@ -12,32 +11,21 @@ struct Input {
     password: String,
 }
 
-fn read_input(filename: &str) -> Result<Vec<Input>, String> {
-    let path = Path::new(filename);
-    let file = File::open(path);
-    let reader = BufReader::new(file.unwrap());
-
+fn read_input(reader: impl Read) -> Result<Vec<Input>, InputError> {
     let re = Regex::new(r"(\d+)-(\d+) (.): (.+)").unwrap();
 
     let mut output = Vec::new();
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                let capture_iter = re.captures_iter(x.as_str());
-                for m in capture_iter {
-                    let i = Input {
-                        pos1: m[1].parse::<usize>().unwrap(),
-                        pos2: m[2].parse::<usize>().unwrap(),
-                        letter: m[3].chars().next().unwrap(),
-                        password: m[4].to_owned(),
-                    };
+    for x in input::read_lines(reader)? {
+        let capture_iter = re.captures_iter(x.as_str());
+        for m in capture_iter {
+            let i = Input {
+                pos1: m[1].parse::<usize>().unwrap(),
+                pos2: m[2].parse::<usize>().unwrap(),
+                letter: m[3].chars().next().unwrap(),
+                password: m[4].to_owned(),
+            };
 
-                    output.push(i);
-                }
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
+            output.push(i);
         }
     }
 
@@ -56,14 +44,12 @@ fn validate_inputs(inputs: Vec<Input>) -> i64 {
     })
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    match read_input(&filename) {
-        Ok(inputs) => println!("valid password: {}", validate_inputs(inputs)),
-        Err(err) => println!("could not parse input {:?}", err),
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let inputs = read_input(reader)?;
+        Ok(validate_inputs(inputs).to_string())
     }
 }
 
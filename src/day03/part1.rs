@@ -1,8 +1,7 @@
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
-use std::path::Path;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::io::Read;
 
 struct Map {
     width: usize,
@@ -10,33 +9,22 @@ struct Map {
     trees: Vec<bool>,
 }
 
-fn read_input(filename: &str) -> Result<Map, String> {
-    let path = Path::new(filename);
-    let file = File::open(path);
-    let reader = BufReader::new(file.unwrap());
-
+fn read_input(reader: impl Read) -> Result<Map, InputError> {
     let mut trees = Vec::new();
     let mut max_width = 0;
     let mut height = 0;
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                let mut line_width = 0;
-                for c in x.chars() {
-                    if c == '.' || c == '#' {
-                        line_width += 1;
-                        trees.push(c == '#');
-                    }
-                }
-                if line_width > max_width {
-                    max_width = line_width;
-                }
-                height += 1;
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
+    for x in input::read_lines(reader)? {
+        let mut line_width = 0;
+        for c in x.chars() {
+            if c == '.' || c == '#' {
+                line_width += 1;
+                trees.push(c == '#');
             }
         }
+        if line_width > max_width {
+            max_width = line_width;
+        }
+        height += 1;
     }
 
     Ok(Map {
@@ -61,16 +49,13 @@ fn count_trees(map: Map) -> i64 {
     trees
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let map = read_input(&filename).unwrap();
-
-    println!("map {:?} {:?} {:?}", map.width, map.height, map.trees);
-
-    println!("trees {:?} ", count_trees(map));
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let map = read_input(reader)?;
+        Ok(count_trees(map).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,7 @@
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
-use std::path::Path;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::io::Read;
 
 struct Map {
     width: usize,
@@ -10,33 +9,22 @@ struct Map {
     trees: Vec<bool>,
 }
 
-fn read_input(filename: &str) -> Result<Map, String> {
-    let path = Path::new(filename);
-    let file = File::open(path);
-    let reader = BufReader::new(file.unwrap());
-
+fn read_input(reader: impl Read) -> Result<Map, InputError> {
     let mut trees = Vec::new();
     let mut max_width = 0;
     let mut height = 0;
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                let mut line_width = 0;
-                for c in x.chars() {
-                    if c == '.' || c == '#' {
-                        line_width += 1;
-                        trees.push(c == '#');
-                    }
-                }
-                if line_width > max_width {
-                    max_width = line_width;
-                }
-                height += 1;
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
+    for x in input::read_lines(reader)? {
+        let mut line_width = 0;
+        for c in x.chars() {
+            if c == '.' || c == '#' {
+                line_width += 1;
+                trees.push(c == '#');
             }
         }
+        if line_width > max_width {
+            max_width = line_width;
+        }
+        height += 1;
     }
 
     Ok(Map {
@@ -61,27 +49,18 @@ fn count_trees(stride_x: usize, stride_y: usize, map: &Map) -> i64 {
     trees
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Solver;
 
-    let filename = args.get(1).unwrap();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let map = read_input(reader)?;
 
-    let map = read_input(&filename).unwrap();
-
-    let t1 = count_trees(1, 1, &map);
-    let t2 = count_trees(3, 1, &map);
-    let t3 = count_trees(5, 1, &map);
-    let t4 = count_trees(7, 1, &map);
-    let t5 = count_trees(1, 2, &map);
-    println!(
-        "{} * {} * {} * {} * {} = {}",
-        t1,
-        t2,
-        t3,
-        t4,
-        t5,
-        t1 * t2 * t3 * t4 * t5
-    );
+        let answer: i64 = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|&(stride_x, stride_y)| count_trees(stride_x, stride_y, &map))
+            .product();
+        Ok(answer.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -1,42 +1,23 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
-
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<Vec<HashMap<String, String>>, String> {
-    let reader = BufReader::new(reader);
 
+fn read_input(reader: impl Read) -> Result<Vec<HashMap<String, String>>, InputError> {
     let mut passports: Vec<HashMap<String, String>> = Vec::new();
-    let mut curr_passport = HashMap::new();
 
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                if x.is_empty() {
-                    passports.push(curr_passport);
-                    curr_passport = HashMap::new();
-                } else {
-                    for field in x.split_ascii_whitespace() {
-                        let kv: Vec<&str> = field.split(':').collect();
-                        curr_passport.insert(kv[0].to_owned(), kv[1].to_owned());
-                    }
+    for group in input::read_groups(reader)? {
+        let mut curr_passport = HashMap::new();
+        for x in group {
+            for field in x.split_ascii_whitespace() {
+                let kv: Vec<&str> = field.split(':').collect();
+                if kv.len() != 2 {
+                    return Err(InputError::invalid(format!("invalid field {:?}", field)));
                 }
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
+                curr_passport.insert(kv[0].to_owned(), kv[1].to_owned());
             }
         }
-    }
-    if !curr_passport.is_empty() {
         passports.push(curr_passport);
     }
 
@@ -45,34 +26,30 @@ fn read_input(reader: impl Read) -> Result<Vec<HashMap<String, String>>, String>
 
 fn count_valid_passports(passports: Vec<HashMap<String, String>>) -> i64 {
     let mut valids = 0;
-    let mandatory_fields = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    let mandatory_fields = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
     for passport in passports.iter() {
         let mut is_valid = true;
         for mf in mandatory_fields.iter() {
             if !passport.contains_key(mf.to_owned()) {
-                println!("did not find mandatory {} in {:?}", mf, passport);
                 is_valid = false;
                 break;
             }
         }
 
         if is_valid {
-            println!("valid {:?} {}", passport, passport.len());
             valids += 1;
         }
     }
     valids
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let input_file = open_input(&filename);
-    let passports = read_input(input_file.unwrap()).unwrap();
-
-    println!("valid passports {:?} ", count_valid_passports(passports));
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let passports = read_input(reader)?;
+        Ok(count_valid_passports(passports).to_string())
+    }
 }
 
 #[cfg(test)]
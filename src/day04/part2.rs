@@ -1,44 +1,24 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use regex::Regex;
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
-
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<Vec<HashMap<String, String>>, String> {
-    let reader = BufReader::new(reader);
 
+fn read_input(reader: impl Read) -> Result<Vec<HashMap<String, String>>, InputError> {
     let mut passports: Vec<HashMap<String, String>> = Vec::new();
-    let mut curr_passport = HashMap::new();
-
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                if x.is_empty() {
-                    passports.push(curr_passport);
-                    curr_passport = HashMap::new();
-                } else {
-                    for field in x.split_ascii_whitespace() {
-                        //println!("field: {:?}", field);
-                        let kv: Vec<&str> = field.split(':').collect();
-                        curr_passport.insert(kv[0].to_owned(), kv[1].to_owned());
-                    }
+
+    for group in input::read_groups(reader)? {
+        let mut curr_passport = HashMap::new();
+        for x in group {
+            for field in x.split_ascii_whitespace() {
+                let kv: Vec<&str> = field.split(':').collect();
+                if kv.len() != 2 {
+                    return Err(InputError::invalid(format!("invalid field {:?}", field)));
                 }
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
+                curr_passport.insert(kv[0].to_owned(), kv[1].to_owned());
             }
         }
-    }
-    if !curr_passport.is_empty() {
         passports.push(curr_passport);
     }
 
@@ -48,7 +28,6 @@ fn read_input(reader: impl Read) -> Result<Vec<HashMap<String, String>>, String>
 fn is_number_in_range(num: &str, min: i64, max: i64) -> bool {
     let n: i64 = num.parse().unwrap_or_default();
     if n < min || n > max {
-        println!("invalid {:?} not in {}-{}", num, min, max);
         return false;
     }
     true
@@ -67,13 +46,12 @@ fn is_valid_height(hgt: &str) -> bool {
         let v: i64 = vs.parse().unwrap_or_default();
 
         if t == "cm" {
-            return v >= 150 && v <= 193;
+            return (150..=193).contains(&v);
         }
         if t == "in" {
-            return v >= 59 && v <= 76;
+            return (59..=76).contains(&v);
         }
     }
-    println!("invalid height {:?}", hgt);
     false
 }
 
@@ -83,7 +61,7 @@ fn is_valid_hcl(color: &str) -> bool {
 }
 
 fn is_valid_ecl(color: &str) -> bool {
-    let mandatory_fields = vec!["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    let mandatory_fields = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
 
     mandatory_fields.contains(&color)
 }
@@ -95,12 +73,11 @@ fn is_valid_pid(pid: &str) -> bool {
 
 fn count_valid_passports(passports: Vec<HashMap<String, String>>) -> i64 {
     let mut valids = 0;
-    let mandatory_fields = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    let mandatory_fields = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
     for passport in passports.iter() {
         let mut is_valid = true;
         for mf in mandatory_fields.iter() {
             if !passport.contains_key(mf.to_owned()) {
-                println!("did not find mandatory {} in {:?}", mf, passport);
                 is_valid = false;
                 break;
             }
@@ -117,25 +94,23 @@ fn count_valid_passports(passports: Vec<HashMap<String, String>>) -> i64 {
         }
 
         if is_valid {
-            println!("valid {:?} {}", passport, passport.len());
             valids += 1;
         }
     }
     valids
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let input_file = open_input(&filename);
-    let passports = read_input(input_file.unwrap()).unwrap();
-
-    println!("valid passports {:?} ", count_valid_passports(passports));
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let passports = read_input(reader)?;
+        Ok(count_valid_passports(passports).to_string())
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -0,0 +1,68 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::io::Read;
+
+fn read_input(reader: impl Read) -> Result<Vec<String>, InputError> {
+    input::read_lines(reader)
+}
+
+fn parse_row(pass: &str) -> i64 {
+    pass.chars()
+        .take(7)
+        .fold(0, |v, x| (v << 1) + if x == 'B' { 1 } else { 0 })
+}
+
+fn parse_column(pass: &str) -> i64 {
+    pass.chars()
+        .skip(7)
+        .take(3)
+        .fold(0, |v, x| (v << 1) + if x == 'R' { 1 } else { 0 })
+}
+
+fn get_seat_id(pass: &str) -> i64 {
+    parse_row(pass) * 8 + parse_column(pass)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let passes = read_input(reader)?;
+
+        let mut seat_ids: Vec<i64> = passes.iter().map(|pass| get_seat_id(pass)).collect();
+
+        seat_ids.sort_unstable();
+
+        match seat_ids.windows(2).find(|ids| ids[1] - ids[0] > 1) {
+            Some(ids) => Ok((ids[0] + 1).to_string()),
+            None => Err(InputError::invalid("no free seat found")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_row() {
+        assert_eq!(parse_row(&String::from("BFFFBBFRRR")), 70);
+        assert_eq!(parse_row(&String::from("FFFBBBFRRR")), 14);
+        assert_eq!(parse_row(&String::from("BBFFBBFRLL")), 102);
+    }
+
+    #[test]
+    fn test_parse_column() {
+        assert_eq!(parse_column(&String::from("BFFFBBFRRR")), 7);
+        assert_eq!(parse_column(&String::from("FFFBBBFRRR")), 7);
+        assert_eq!(parse_column(&String::from("BBFFBBFRLL")), 4);
+    }
+
+    #[test]
+    fn test_parse_seat() {
+        assert_eq!(get_seat_id(&String::from("BFFFBBFRRR")), 567);
+        assert_eq!(get_seat_id(&String::from("FFFBBBFRRR")), 119);
+        assert_eq!(get_seat_id(&String::from("BBFFBBFRLL")), 820);
+    }
+}
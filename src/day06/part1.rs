@@ -0,0 +1,99 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::io::Read;
+
+fn read_input(reader: impl Read) -> Result<Vec<HashSet<char>>, InputError> {
+    let mut groups: Vec<HashSet<char>> = Vec::new();
+
+    for group in input::read_groups(reader)? {
+        let mut curr_group = HashSet::new();
+        for x in group {
+            for c in x.chars() {
+                curr_group.insert(c);
+            }
+        }
+        groups.push(curr_group);
+    }
+
+    Ok(groups)
+}
+
+fn count_answers(groups: Vec<HashSet<char>>) -> usize {
+    groups.iter().fold(0, |c, g| c + g.len())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let groups = read_input(reader)?;
+        Ok(count_answers(groups).to_string())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_reader() {
+        let test_input = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+        let output = read_input(test_input.as_bytes()).unwrap();
+        assert_eq!(output.len(), 5);
+
+        let group = output.first().unwrap();
+
+        assert_eq!(group.len(), 3);
+
+        assert_eq!(group.contains(&'a'), true);
+        assert_eq!(group.contains(&'b'), true);
+        assert_eq!(group.contains(&'c'), true);
+
+        let group3 = output.get(2).unwrap();
+
+        assert_eq!(group3.len(), 3);
+
+        assert_eq!(group3.contains(&'a'), true);
+        assert_eq!(group3.contains(&'b'), true);
+        assert_eq!(group3.contains(&'c'), true);
+    }
+
+    #[test]
+    fn test_count_valid_passports() {
+        let test_input = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+        let output = read_input(test_input.as_bytes()).unwrap();
+
+        assert_eq!(count_answers(output), 11);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::io::Read;
+
+#[derive(Debug)]
+struct Group {
+    people: usize,
+    answers: HashMap<char, usize>,
+}
+
+fn read_input(reader: impl Read) -> Result<Vec<Group>, InputError> {
+    let mut groups: Vec<Group> = Vec::new();
+
+    for group in input::read_groups(reader)? {
+        let mut answers = HashMap::new();
+        for x in group.iter() {
+            for c in x.chars() {
+                answers.entry(c).and_modify(|c| *c += 1).or_insert(1);
+            }
+        }
+        groups.push(Group {
+            answers,
+            people: group.len(),
+        });
+    }
+
+    Ok(groups)
+}
+
+fn count_answers(groups: Vec<Group>) -> usize {
+    groups.iter().fold(0, |total, g| {
+        total + g.answers.values().filter(|&&v| v == g.people).count()
+    })
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let groups = read_input(reader)?;
+        Ok(count_answers(groups).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_reader() {
+        let test_input = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+        let output = read_input(test_input.as_bytes()).unwrap();
+        assert_eq!(output.len(), 5);
+
+        let group = output.first().unwrap();
+
+        assert_eq!(group.people, 1);
+        assert_eq!(group.answers.len(), 3);
+
+        println!("{:?}", group);
+
+        assert_eq!(group.answers.get(&'a').unwrap(), &1);
+        assert_eq!(group.answers.get(&'b').unwrap(), &1);
+        assert_eq!(group.answers.get(&'c').unwrap(), &1);
+
+        let group3 = output.get(2).unwrap();
+        println!("{:?}", group3);
+
+        assert_eq!(group3.people, 2);
+        assert_eq!(group3.answers.len(), 3);
+
+        assert_eq!(group3.answers.get(&'a').unwrap(), &2);
+        assert_eq!(group3.answers.get(&'b').unwrap(), &1);
+        assert_eq!(group3.answers.get(&'c').unwrap(), &1);
+    }
+
+    #[test]
+    fn test_count_valid_passports() {
+        let test_input = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+        let output = read_input(test_input.as_bytes()).unwrap();
+
+        assert_eq!(count_answers(output), 6);
+    }
+}
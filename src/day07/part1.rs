@@ -1,18 +1,10 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use regex::Regex;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
-
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
 
 fn parse_rule(rule: &str) -> Option<(&str, Vec<&str>)> {
     let rule_re = Regex::new(r"^(.*) bags contain (.*).$").unwrap();
@@ -41,25 +33,17 @@ fn parse_rule(rule: &str) -> Option<(&str, Vec<&str>)> {
     None
 }
 
-fn read_input(reader: impl Read) -> Result<HashMap<String, Vec<String>>, String> {
-    let reader = BufReader::new(reader);
-
+fn read_input(reader: impl Read) -> Result<HashMap<String, Vec<String>>, InputError> {
     let mut rules: HashMap<String, Vec<String>> = HashMap::new();
 
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                let (outer, inner) = parse_rule(&x).unwrap();
-                for i in inner {
-                    rules
-                        .entry(i.to_owned())
-                        .and_modify(|a| a.push(outer.to_owned()))
-                        .or_insert(vec![outer.to_owned()]);
-                }
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
+    for (i, x) in input::read_lines(reader)?.iter().enumerate() {
+        let (outer, inner) = parse_rule(x)
+            .ok_or_else(|| InputError::parse(i + 1, format!("invalid rule {:?}", x)))?;
+        for i in inner {
+            rules
+                .entry(i.to_owned())
+                .and_modify(|a| a.push(outer.to_owned()))
+                .or_insert(vec![outer.to_owned()]);
         }
     }
 
@@ -73,30 +57,27 @@ fn traverse_rules(
 ) -> HashSet<String> {
     if let Some(s) = rules.get(color) {
         for outer in s {
-            println!("from {:?} to {:?} {:?}", color, outer, visited);
             visited.insert(outer.to_owned());
             visited = traverse_rules(visited, rules, outer);
         }
     }
-    //println!("{:?}", count);
 
     visited
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let input_file = open_input(&filename);
-    let rules = read_input(input_file.unwrap()).unwrap();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let rules = read_input(reader)?;
 
-    let mut visited = HashSet::new();
-    visited = traverse_rules(visited, &rules, &String::from("shiny gold"));
-    println!("{:?}", visited.len());
+        let visited = traverse_rules(HashSet::new(), &rules, "shiny gold");
+        Ok(visited.len().to_string())
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -1,17 +1,9 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use regex::Regex;
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
-
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
 
 #[derive(Debug, PartialEq)]
 struct Rule {
@@ -56,21 +48,13 @@ fn parse_rule(rule: &str) -> Option<(&str, Vec<Rule>)> {
     None
 }
 
-fn read_input(reader: impl Read) -> Result<HashMap<String, Vec<Rule>>, String> {
-    let reader = BufReader::new(reader);
-
+fn read_input(reader: impl Read) -> Result<HashMap<String, Vec<Rule>>, InputError> {
     let mut rules: HashMap<String, Vec<Rule>> = HashMap::new();
 
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                let (outer, inner) = parse_rule(&x).unwrap();
-                rules.insert(outer.to_owned(), inner);
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
+    for (i, x) in input::read_lines(reader)?.iter().enumerate() {
+        let (outer, inner) = parse_rule(x)
+            .ok_or_else(|| InputError::parse(i + 1, format!("invalid rule {:?}", x)))?;
+        rules.insert(outer.to_owned(), inner);
     }
 
     Ok(rules)
@@ -83,21 +67,19 @@ fn traverse_rules(rules: &HashMap<String, Vec<Rule>>, color: &str, count: i64) -
             c += contained.count * traverse_rules(rules, &contained.bag, 1);
         }
     }
-    println!("returning from {:?} {}", color, c);
 
     c
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let input_file = open_input(&filename);
-    let rules = read_input(input_file.unwrap()).unwrap();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let rules = read_input(reader)?;
 
-    let count = traverse_rules(&rules, &String::from("shiny gold"), 0);
-    println!("{:?}", count);
+        let count = traverse_rules(&rules, "shiny gold", 0);
+        Ok(count.to_string())
+    }
 }
 
 #[cfg(test)]
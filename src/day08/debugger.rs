@@ -0,0 +1,469 @@
+use crate::day08::part1::successor;
+use crate::day08::part1::Instruction;
+use crate::day08::part1::Outcome;
+use crate::day08::part1::CPU;
+use std::collections::BTreeSet;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+
+const HELP: &str = "commands:
+    s, step [n]        execute the next n instructions, default 1
+    c, continue        run until a breakpoint, the end or a loop
+    b, break [ip]      set a breakpoint at ip, or list breakpoints
+    d, delete <ip>     remove the breakpoint at ip
+    w, watch           toggle printing every change of acc
+    p, print           show ip, acc and whether the program has stopped
+    l, list            disassemble the instructions around ip
+    r, reset           start again from the first instruction
+    q, quit            leave the debugger";
+
+// how the jump target of an instruction is shown
+fn describe_target(target: i64, len: usize) -> String {
+    if target == len as i64 {
+        format!("{} (end)", target)
+    } else if target < 0 || target > len as i64 {
+        format!("{} (out of bounds)", target)
+    } else {
+        target.to_string()
+    }
+}
+
+// sources of every jump, indexed by target, with the end at `len`
+fn jump_sources(program: &[Instruction]) -> Vec<Vec<usize>> {
+    let mut sources = vec![Vec::new(); program.len() + 1];
+    for (ip, &instruction) in program.iter().enumerate() {
+        if let Instruction::Jmp(_) = instruction {
+            let target = successor(ip, instruction);
+            if target >= 0 && target as usize <= program.len() {
+                sources[target as usize].push(ip);
+            }
+        }
+    }
+    sources
+}
+
+fn disassemble_line(ip: usize, instruction: Instruction, sources: &[usize], len: usize) -> String {
+    let mut notes = Vec::new();
+    if let Instruction::Jmp(_) = instruction {
+        notes.push(format!(
+            "to {}",
+            describe_target(successor(ip, instruction), len)
+        ));
+    }
+    if !sources.is_empty() {
+        let sources: Vec<String> = sources.iter().map(|s| s.to_string()).collect();
+        notes.push(format!("from {}", sources.join(", ")));
+    }
+
+    if notes.is_empty() {
+        format!("{:5}  {}", ip, instruction)
+    } else {
+        format!(
+            "{:5}  {:<10} ; {}",
+            ip,
+            instruction.to_string(),
+            notes.join("; ")
+        )
+    }
+}
+
+/// Lists the program one instruction per line, annotating every jump with
+/// its target and every jump target with the jumps leading to it.
+pub fn disassemble(program: &[Instruction]) -> String {
+    let sources = jump_sources(program);
+
+    let mut lines: Vec<String> = program
+        .iter()
+        .enumerate()
+        .map(|(ip, &instruction)| disassemble_line(ip, instruction, &sources[ip], program.len()))
+        .collect();
+
+    let end = &sources[program.len()];
+    if !end.is_empty() {
+        let end: Vec<String> = end.iter().map(|s| s.to_string()).collect();
+        lines.push(format!(
+            "{:5}  {:<10} ; from {}",
+            program.len(),
+            "end",
+            end.join(", ")
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// The control flow graph in Graphviz DOT format. Solid edges are taken by
+/// the program as it is, dashed edges are taken if a `nop` or `jmp` is
+/// flipped.
+pub fn control_flow_dot(program: &[Instruction]) -> String {
+    let len = program.len() as i64;
+    let node = |target: i64| {
+        if target == len {
+            String::from("end")
+        } else if target < 0 || target > len {
+            String::from("out")
+        } else {
+            format!("i{}", target)
+        }
+    };
+
+    let mut lines = vec![
+        String::from("digraph program {"),
+        String::from("    node [shape=box, fontname=monospace];"),
+        String::from("    end [label=\"end\", shape=doublecircle];"),
+    ];
+
+    let mut out_of_bounds = false;
+    let mut edges = Vec::new();
+    for (ip, &instruction) in program.iter().enumerate() {
+        lines.push(format!("    i{} [label=\"{}: {}\"];", ip, ip, instruction));
+
+        let target = successor(ip, instruction);
+        out_of_bounds |= node(target) == "out";
+        edges.push(format!("    i{} -> {};", ip, node(target)));
+
+        if let Some(flipped) = instruction.flipped() {
+            let target = successor(ip, flipped);
+            out_of_bounds |= node(target) == "out";
+            edges.push(format!(
+                "    i{} -> {} [style=dashed, label=\"flip\"];",
+                ip,
+                node(target)
+            ));
+        }
+    }
+
+    if out_of_bounds {
+        lines.push(String::from(
+            "    out [label=\"out of bounds\", shape=octagon];",
+        ));
+    }
+    lines.extend(edges);
+    lines.push(String::from("}"));
+
+    lines.join("\n")
+}
+
+/// Interactive stepper for boot code, driven by commands read line by line.
+pub struct Debugger {
+    program: Vec<Instruction>,
+    cpu: CPU,
+    executed: Vec<bool>,
+    breakpoints: BTreeSet<usize>,
+    watch: bool,
+}
+
+impl Debugger {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Debugger {
+            cpu: CPU::new(program.clone()),
+            executed: vec![false; program.len()],
+            program,
+            breakpoints: BTreeSet::new(),
+            watch: false,
+        }
+    }
+
+    // why execution cannot or should not go on from the current ip
+    fn status(&self) -> Option<Outcome> {
+        let acc = self.cpu.acc;
+        if self.cpu.ip == self.program.len() as i64 {
+            return Some(Outcome::Halt { acc });
+        }
+        if self.cpu.current().is_none() {
+            return Some(Outcome::OutOfBounds {
+                ip: self.cpu.ip,
+                acc,
+            });
+        }
+
+        let ip = self.cpu.ip as usize;
+        if self.executed[ip] {
+            return Some(Outcome::Loop { ip, acc });
+        }
+        None
+    }
+
+    fn show_current(&self, output: &mut impl Write) -> io::Result<()> {
+        match self.cpu.current() {
+            Some(instruction) => writeln!(
+                output,
+                "{:5}  {:<10} acc {}",
+                self.cpu.ip,
+                instruction.to_string(),
+                self.cpu.acc
+            ),
+            None => self.show_status(output),
+        }
+    }
+
+    fn show_status(&self, output: &mut impl Write) -> io::Result<()> {
+        match self.status() {
+            Some(Outcome::Halt { acc }) => writeln!(output, "halted, acc {}", acc),
+            Some(Outcome::OutOfBounds { ip, acc }) => {
+                writeln!(output, "jumped out of bounds to {}, acc {}", ip, acc)
+            }
+            Some(Outcome::Loop { ip, acc }) => {
+                writeln!(output, "ip {} was already executed, acc {}", ip, acc)
+            }
+            None => writeln!(output, "ip {}, acc {}", self.cpu.ip, self.cpu.acc),
+        }
+    }
+
+    // shows where execution stopped, with a warning when it is looping
+    fn show_stop(&self, output: &mut impl Write) -> io::Result<()> {
+        if let Some(Outcome::Loop { .. }) = self.status() {
+            self.show_status(output)?;
+        }
+        self.show_current(output)
+    }
+
+    // executes one instruction, false if the program has left its bounds
+    fn step(&mut self, output: &mut impl Write) -> io::Result<bool> {
+        let ip = self.cpu.ip;
+        let acc = self.cpu.acc;
+        if self.cpu.execute().is_none() {
+            return Ok(false);
+        }
+        self.executed[ip as usize] = true;
+
+        if self.watch && self.cpu.acc != acc {
+            writeln!(output, "acc {} -> {} at ip {}", acc, self.cpu.acc, ip)?;
+        }
+        Ok(true)
+    }
+
+    fn continue_until_stop(&mut self, output: &mut impl Write) -> io::Result<()> {
+        // always move at least one instruction so that continuing from a
+        // breakpoint or a detected loop makes progress
+        if self.step(output)? {
+            while self.status().is_none() {
+                if self.breakpoints.contains(&(self.cpu.ip as usize)) {
+                    writeln!(output, "breakpoint at {}", self.cpu.ip)?;
+                    break;
+                }
+                self.step(output)?;
+            }
+        }
+
+        self.show_stop(output)
+    }
+
+    fn list(&self, output: &mut impl Write) -> io::Result<()> {
+        let sources = jump_sources(&self.program);
+        let ip = self.cpu.ip.max(0) as usize;
+        let first = ip.saturating_sub(3);
+
+        for (i, &instruction) in self.program.iter().enumerate().take(ip + 4).skip(first) {
+            let marker = if i as i64 == self.cpu.ip { ">" } else { " " };
+            let line = disassemble_line(i, instruction, &sources[i], self.program.len());
+            writeln!(output, "{}{}", marker, line)?;
+        }
+        Ok(())
+    }
+
+    fn parse_ip(&self, arg: Option<&str>) -> Result<usize, String> {
+        let arg = arg.ok_or("missing instruction address")?;
+        let ip: usize = arg
+            .parse()
+            .map_err(|_| format!("invalid instruction address {:?}", arg))?;
+        if ip >= self.program.len() {
+            return Err(format!("no instruction at {}", ip));
+        }
+        Ok(ip)
+    }
+
+    // runs one command, false when the debugger should quit
+    fn command(&mut self, line: &str, output: &mut impl Write) -> io::Result<bool> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("step");
+        let arg = words.next();
+
+        match command {
+            "s" | "step" => match arg.map(|n| n.parse::<usize>()).unwrap_or(Ok(1)) {
+                Ok(n) => {
+                    for _ in 0..n {
+                        if !self.step(output)? {
+                            break;
+                        }
+                    }
+                    self.show_stop(output)?;
+                }
+                Err(_) => writeln!(output, "invalid step count {:?}", arg.unwrap_or(""))?,
+            },
+            "c" | "continue" => self.continue_until_stop(output)?,
+            "b" | "break" if arg.is_none() => {
+                let breakpoints: Vec<String> =
+                    self.breakpoints.iter().map(|b| b.to_string()).collect();
+                writeln!(output, "breakpoints: {}", breakpoints.join(", "))?;
+            }
+            "b" | "break" => match self.parse_ip(arg) {
+                Ok(ip) => {
+                    self.breakpoints.insert(ip);
+                    writeln!(output, "breakpoint at {}", ip)?;
+                }
+                Err(err) => writeln!(output, "{}", err)?,
+            },
+            "d" | "delete" => match self.parse_ip(arg) {
+                Ok(ip) if self.breakpoints.remove(&ip) => {
+                    writeln!(output, "deleted breakpoint at {}", ip)?
+                }
+                Ok(ip) => writeln!(output, "no breakpoint at {}", ip)?,
+                Err(err) => writeln!(output, "{}", err)?,
+            },
+            "w" | "watch" => {
+                self.watch = !self.watch;
+                let state = if self.watch { "on" } else { "off" };
+                writeln!(output, "watching acc {}", state)?;
+            }
+            "p" | "print" => self.show_status(output)?,
+            "l" | "list" => self.list(output)?,
+            "r" | "reset" => {
+                self.cpu = CPU::new(self.program.clone());
+                self.executed = vec![false; self.program.len()];
+                self.show_current(output)?;
+            }
+            "q" | "quit" => return Ok(false),
+            "h" | "help" => writeln!(output, "{}", HELP)?,
+            _ => writeln!(output, "unknown command {:?}, try help", command)?,
+        }
+        Ok(true)
+    }
+
+    /// Reads commands until `quit` or the end of the input. An empty line
+    /// steps one instruction.
+    pub fn run(&mut self, input: impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        self.show_current(output)?;
+
+        let mut lines = input.lines();
+        loop {
+            write!(output, "(vm) ")?;
+            output.flush()?;
+
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+            if !self.command(&line, output)? {
+                break;
+            }
+        }
+        writeln!(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day08::part1::read_input;
+
+    fn get_test_program() -> Vec<Instruction> {
+        read_input(include_str!("sample.txt").as_bytes()).unwrap()
+    }
+
+    fn run_commands(commands: &str) -> String {
+        let mut debugger = Debugger::new(get_test_program());
+        let mut output = Vec::new();
+        debugger.run(commands.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let expected = "    0  nop +0
+    1  acc +1     ; from 4
+    2  jmp +4     ; to 6
+    3  acc +3     ; from 7
+    4  jmp -3     ; to 1
+    5  acc -99
+    6  acc +1     ; from 2
+    7  jmp -4     ; to 3
+    8  acc +6";
+        assert_eq!(disassemble(&get_test_program()), expected);
+
+        let program = read_input("jmp +2\nacc +1\njmp -5".as_bytes()).unwrap();
+        let expected = "    0  jmp +2     ; to 2
+    1  acc +1
+    2  jmp -5     ; to -3 (out of bounds); from 0";
+        assert_eq!(disassemble(&program), expected);
+
+        let program = read_input("jmp +1".as_bytes()).unwrap();
+        assert_eq!(
+            disassemble(&program),
+            "    0  jmp +1     ; to 1 (end)\n    1  end        ; from 0"
+        );
+    }
+
+    #[test]
+    fn test_control_flow_dot() {
+        let program = read_input("nop +2\nacc +1\njmp -3".as_bytes()).unwrap();
+        let expected = "digraph program {
+    node [shape=box, fontname=monospace];
+    end [label=\"end\", shape=doublecircle];
+    i0 [label=\"0: nop +2\"];
+    i1 [label=\"1: acc +1\"];
+    i2 [label=\"2: jmp -3\"];
+    out [label=\"out of bounds\", shape=octagon];
+    i0 -> i1;
+    i0 -> i2 [style=dashed, label=\"flip\"];
+    i1 -> i2;
+    i2 -> out;
+    i2 -> end [style=dashed, label=\"flip\"];
+}";
+        assert_eq!(control_flow_dot(&program), expected);
+    }
+
+    #[test]
+    fn test_step() {
+        let output = run_commands("s\n\ns 2\np\nq\n");
+        let expected = "    0  nop +0     acc 0
+(vm)     1  acc +1     acc 0
+(vm)     2  jmp +4     acc 1
+(vm)     7  jmp -4     acc 2
+(vm) ip 7, acc 2
+(vm) \n";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_continue_and_breakpoints() {
+        let output = run_commands("b 6\nb\nc\nw\nc\nd 6\nd 6\nc\n");
+        let expected = "    0  nop +0     acc 0
+(vm) breakpoint at 6
+(vm) breakpoints: 6
+(vm) breakpoint at 6
+    6  acc +1     acc 1
+(vm) watching acc on
+(vm) acc 1 -> 2 at ip 6
+acc 2 -> 5 at ip 3
+ip 1 was already executed, acc 5
+    1  acc +1     acc 5
+(vm) deleted breakpoint at 6
+(vm) no breakpoint at 6
+(vm) acc 5 -> 6 at ip 1
+ip 2 was already executed, acc 6
+    2  jmp +4     acc 6
+(vm) \n";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_halt_and_reset() {
+        let mut debugger = Debugger::new(read_input("acc +3\njmp +1".as_bytes()).unwrap());
+        let mut output = Vec::new();
+        debugger
+            .run("c\ns\nr\nl\nx\n".as_bytes(), &mut output)
+            .unwrap();
+
+        let expected = "    0  acc +3     acc 0
+(vm) halted, acc 3
+(vm) halted, acc 3
+(vm)     0  acc +3     acc 0
+(vm) >    0  acc +3
+     1  jmp +1     ; to 2 (end)
+(vm) unknown command \"x\", try help
+(vm) \n";
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::fmt;
+use std::io::Read;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Instruction {
+    Nop(i64),
+    Acc(i64),
+    Jmp(i64),
+}
+
+impl Instruction {
+    /// The instruction with `nop` and `jmp` swapped, None for `acc`.
+    pub(crate) fn flipped(self) -> Option<Instruction> {
+        match self {
+            Instruction::Nop(arg) => Some(Instruction::Jmp(arg)),
+            Instruction::Jmp(arg) => Some(Instruction::Nop(arg)),
+            Instruction::Acc(_) => None,
+        }
+    }
+}
+
+/// Address of the instruction executed after `instruction` at `ip`.
+pub(crate) fn successor(ip: usize, instruction: Instruction) -> i64 {
+    match instruction {
+        Instruction::Jmp(arg) => ip as i64 + arg,
+        _ => ip as i64 + 1,
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Nop(arg) => write!(f, "nop {:+}", arg),
+            Instruction::Acc(arg) => write!(f, "acc {:+}", arg),
+            Instruction::Jmp(arg) => write!(f, "jmp {:+}", arg),
+        }
+    }
+}
+
+/// How a program run ended.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Outcome {
+    /// Ran past the last instruction.
+    Halt { acc: i64 },
+    /// Was about to run the instruction at `ip` a second time.
+    Loop { ip: usize, acc: i64 },
+    /// Jumped to `ip`, which is not in the program nor right after it.
+    OutOfBounds { ip: i64, acc: i64 },
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq)]
+pub(crate) struct CPU {
+    pub(crate) ip: i64,
+    pub(crate) inst: Vec<Instruction>,
+    pub(crate) acc: i64,
+}
+
+fn parse_instruction(s: &str) -> Result<Instruction, String> {
+    let mut i = s.split_whitespace();
+
+    let name = i.next().ok_or("missing instruction")?;
+    let arg = i
+        .next()
+        .ok_or_else(|| format!("missing argument for {}", name))?;
+    if let Some(extra) = i.next() {
+        return Err(format!("unexpected {:?} after argument", extra));
+    }
+
+    let arg: i64 = arg
+        .parse()
+        .map_err(|_| format!("invalid argument {:?}", arg))?;
+
+    match name {
+        "nop" => Ok(Instruction::Nop(arg)),
+        "acc" => Ok(Instruction::Acc(arg)),
+        "jmp" => Ok(Instruction::Jmp(arg)),
+        _ => Err(format!("unknown instruction {:?}", name)),
+    }
+}
+
+pub fn read_input(reader: impl Read) -> Result<Vec<Instruction>, InputError> {
+    let mut program: Vec<Instruction> = Vec::new();
+
+    for (i, x) in input::read_lines(reader)?.iter().enumerate() {
+        let instruction = parse_instruction(x).map_err(|err| InputError::parse(i + 1, err))?;
+        program.push(instruction);
+    }
+
+    Ok(program)
+}
+
+impl CPU {
+    pub(crate) fn new(program: Vec<Instruction>) -> Self {
+        CPU {
+            ip: 0,
+            inst: program,
+            acc: 0,
+        }
+    }
+
+    /// The instruction at `ip`, None if `ip` is outside the program.
+    pub(crate) fn current(&self) -> Option<Instruction> {
+        if self.ip < 0 {
+            return None;
+        }
+        self.inst.get(self.ip as usize).copied()
+    }
+
+    /// Executes the instruction at `ip` and returns the address of the next
+    /// instruction to execute, or None if `ip` is outside the program.
+    pub(crate) fn execute(&mut self) -> Option<i64> {
+        let instr = self.current()?;
+
+        match instr {
+            Instruction::Nop(_) => {
+                self.ip += 1;
+            }
+            Instruction::Acc(arg) => {
+                self.acc += arg;
+                self.ip += 1;
+            }
+            Instruction::Jmp(arg) => {
+                self.ip += arg;
+            }
+        }
+        Some(self.ip)
+    }
+
+    /// Runs until the program halts, leaves the program or is about to
+    /// execute an instruction for the second time.
+    pub(crate) fn run(&mut self) -> Outcome {
+        let mut executed = vec![false; self.inst.len()];
+
+        loop {
+            if self.ip == self.inst.len() as i64 {
+                return Outcome::Halt { acc: self.acc };
+            }
+            if self.current().is_none() {
+                return Outcome::OutOfBounds {
+                    ip: self.ip,
+                    acc: self.acc,
+                };
+            }
+
+            let ip = self.ip as usize;
+            if executed[ip] {
+                return Outcome::Loop { ip, acc: self.acc };
+            }
+            executed[ip] = true;
+
+            self.execute();
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let program = read_input(reader)?;
+
+        match CPU::new(program).run() {
+            Outcome::Loop { acc, .. } => Ok(acc.to_string()),
+            outcome => Err(InputError::invalid(format!(
+                "program does not loop: {:?}",
+                outcome
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_reader() {
+        let test_input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+        let output = read_input(test_input.as_bytes()).unwrap();
+        println!("{:?}", output);
+
+        let expected = vec![
+            Instruction::Nop(0),
+            Instruction::Acc(1),
+            Instruction::Jmp(4),
+            Instruction::Acc(3),
+            Instruction::Jmp(-3),
+            Instruction::Acc(-99),
+            Instruction::Acc(1),
+            Instruction::Jmp(-4),
+            Instruction::Acc(6),
+        ];
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_execute() {
+        let test_input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+        let opcodes = read_input(test_input.as_bytes()).unwrap();
+
+        let mut cpu = CPU::new(opcodes);
+
+        /*
+        0 nop +0  | 1
+        1 acc +1  | 2, 8(!)
+        2 jmp +4  | 3
+        3 acc +3  | 6
+        4 jmp -3  | 7
+        5 acc -99 |
+        6 acc +1  | 4
+        7 jmp -4  | 5
+        8 acc +6  |
+        */
+
+        assert_eq!(cpu.ip, 0);
+        assert_eq!(cpu.execute(), Some(1));
+        assert_eq!(cpu.execute(), Some(2));
+        assert_eq!(cpu.execute(), Some(6));
+        assert_eq!(cpu.execute(), Some(7));
+        assert_eq!(cpu.execute(), Some(3));
+        assert_eq!(cpu.execute(), Some(4));
+        assert_eq!(cpu.execute(), Some(1));
+
+        println!("{:?}", cpu.acc);
+
+        assert_eq!(cpu.acc, 5);
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!(parse_instruction("acc +12345"), Ok(Instruction::Acc(12345)));
+        assert_eq!(
+            parse_instruction("jmp -12345"),
+            Ok(Instruction::Jmp(-12345))
+        );
+        assert_eq!(
+            parse_instruction("someinstr +12345"),
+            Err(String::from("unknown instruction \"someinstr\""))
+        );
+        assert!(parse_instruction("nop").is_err());
+        assert!(parse_instruction("nop +1 +2").is_err());
+        assert!(parse_instruction("nop x").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Instruction::Nop(0).to_string(), "nop +0");
+        assert_eq!(Instruction::Jmp(-3).to_string(), "jmp -3");
+        assert_eq!(
+            parse_instruction(&Instruction::Acc(42).to_string()),
+            Ok(Instruction::Acc(42))
+        );
+    }
+
+    #[test]
+    fn test_read_input_error() {
+        match read_input("nop +0\nacc +1\nmul +2".as_bytes()) {
+            Err(InputError::Parse { line, message }) => {
+                assert_eq!(line, 3);
+                assert_eq!(message, "unknown instruction \"mul\"");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let program = read_input(include_str!("sample.txt").as_bytes()).unwrap();
+        assert_eq!(CPU::new(program).run(), Outcome::Loop { ip: 1, acc: 5 });
+
+        let program = read_input("acc +2\nnop +0".as_bytes()).unwrap();
+        assert_eq!(CPU::new(program).run(), Outcome::Halt { acc: 2 });
+
+        let program = read_input("acc +2\njmp -2".as_bytes()).unwrap();
+        assert_eq!(
+            CPU::new(program).run(),
+            Outcome::OutOfBounds { ip: -1, acc: 2 }
+        );
+
+        let program = read_input("jmp +3\nnop +0".as_bytes()).unwrap();
+        assert_eq!(
+            CPU::new(program).run(),
+            Outcome::OutOfBounds { ip: 3, acc: 0 }
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::day08::part1::read_input;
+use crate::day08::part1::successor;
+use crate::day08::part1::Instruction;
+use crate::day08::part1::Outcome;
+use crate::day08::part1::CPU;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::io::Read;
+
+// for every instruction, whether running from it ends right after the last
+// instruction; found by walking back from the end along reversed edges
+fn reaches_end(program: &[Instruction]) -> Vec<bool> {
+    let len = program.len();
+
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); len + 1];
+    for (ip, &instruction) in program.iter().enumerate() {
+        let next = successor(ip, instruction);
+        if next >= 0 && next as usize <= len {
+            predecessors[next as usize].push(ip);
+        }
+    }
+
+    let mut reaches = vec![false; len + 1];
+    reaches[len] = true;
+    let mut stack = vec![len];
+    while let Some(ip) = stack.pop() {
+        for &prev in &predecessors[ip] {
+            if !reaches[prev] {
+                reaches[prev] = true;
+                stack.push(prev);
+            }
+        }
+    }
+
+    reaches.truncate(len);
+    reaches
+}
+
+/// Finds the one `nop` or `jmp` that has to be flipped for the program to
+/// halt. Returns its index and the accumulator after the repaired program
+/// halts.
+pub(crate) fn repair_program(program: &[Instruction]) -> Option<(usize, i64)> {
+    let reaches = reaches_end(program);
+    let halts_from = |ip: i64| ip == program.len() as i64 || (ip >= 0 && reaches[ip as usize]);
+
+    // follow the original program until it is one flip away from the end
+    let mut visited = vec![false; program.len()];
+    let mut ip: i64 = 0;
+    let flip = loop {
+        if ip < 0 || ip as usize >= program.len() || visited[ip as usize] {
+            return None;
+        }
+        let current = ip as usize;
+        visited[current] = true;
+
+        let instruction = program[current];
+        if let Some(flipped) = instruction.flipped() {
+            if halts_from(successor(current, flipped)) {
+                break current;
+            }
+        }
+        ip = successor(current, instruction);
+    };
+
+    let mut repaired = program.to_vec();
+    repaired[flip] = repaired[flip].flipped()?;
+
+    match CPU::new(repaired).run() {
+        Outcome::Halt { acc } => Some((flip, acc)),
+        _ => None,
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let program = read_input(reader)?;
+
+        repair_program(&program)
+            .map(|(_, acc)| acc.to_string())
+            .ok_or_else(|| InputError::invalid("no single instruction fix terminates"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_reader() {
+        let test_input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+        let output = read_input(test_input.as_bytes()).unwrap();
+        println!("{:?}", output);
+
+        let expected = vec![
+            Instruction::Nop(0),
+            Instruction::Acc(1),
+            Instruction::Jmp(4),
+            Instruction::Acc(3),
+            Instruction::Jmp(-3),
+            Instruction::Acc(-99),
+            Instruction::Acc(1),
+            Instruction::Jmp(-4),
+            Instruction::Acc(6),
+        ];
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_execute() {
+        let test_input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+        let opcodes = read_input(test_input.as_bytes()).unwrap();
+
+        let mut cpu = CPU::new(opcodes);
+
+        /*
+        0 nop +0  | 1
+        1 acc +1  | 2, 8(!)
+        2 jmp +4  | 3
+        3 acc +3  | 6
+        4 jmp -3  | 7
+        5 acc -99 |
+        6 acc +1  | 4
+        7 jmp -4  | 5
+        8 acc +6  |
+        */
+
+        assert_eq!(cpu.ip, 0);
+        assert_eq!(cpu.execute(), Some(1));
+        assert_eq!(cpu.execute(), Some(2));
+        assert_eq!(cpu.execute(), Some(6));
+        assert_eq!(cpu.execute(), Some(7));
+        assert_eq!(cpu.execute(), Some(3));
+        assert_eq!(cpu.execute(), Some(4));
+        assert_eq!(cpu.execute(), Some(1));
+
+        println!("{:?}", cpu.acc);
+
+        assert_eq!(cpu.acc, 5);
+    }
+
+    #[test]
+    fn test_execute_infinite() {
+        let test_input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+        let output = read_input(test_input.as_bytes()).unwrap();
+        assert_eq!(CPU::new(output).run(), Outcome::Loop { ip: 1, acc: 5 });
+    }
+
+    #[test]
+    fn test_execute_infinite_2() {
+        let test_input = "jmp +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+        let output = read_input(test_input.as_bytes()).unwrap();
+        assert_eq!(CPU::new(output).run(), Outcome::Loop { ip: 0, acc: 0 });
+    }
+
+    #[test]
+    fn test_execute_finite() {
+        let test_input = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+nop -4
+acc +6";
+        let output = read_input(test_input.as_bytes()).unwrap();
+        assert_eq!(CPU::new(output).run(), Outcome::Halt { acc: 8 });
+    }
+
+    #[test]
+    fn test_reaches_end() {
+        let program = read_input(include_str!("sample.txt").as_bytes()).unwrap();
+
+        assert_eq!(
+            reaches_end(&program),
+            vec![false, false, false, false, false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn test_repair_program() {
+        let program = read_input(include_str!("sample.txt").as_bytes()).unwrap();
+        assert_eq!(repair_program(&program), Some((7, 8)));
+
+        // flipping the nop at 1 would also work, but the jmp at 3 runs first
+        let program = read_input("jmp +2\nnop +3\nacc +4\njmp -2\nacc +1".as_bytes()).unwrap();
+        assert_eq!(repair_program(&program), Some((3, 5)));
+
+        let program = read_input("acc +1\njmp +0\njmp -2".as_bytes()).unwrap();
+        assert_eq!(repair_program(&program), None);
+    }
+}
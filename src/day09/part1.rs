@@ -1,39 +1,21 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use std::collections::vec_deque::VecDeque;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
 
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<Vec<i64>, String> {
-    let reader = BufReader::new(reader);
-
-    let mut lines: Vec<i64> = Vec::new();
-
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                lines.push(x.parse().unwrap());
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
-    }
+const PREAMBLE: usize = 25;
 
-    Ok(lines)
+fn read_input(reader: impl Read) -> Result<Vec<i64>, InputError> {
+    input::read_parsed(reader)
 }
 
-fn find_first_not_sum(previous: &mut VecDeque<i64>, input: VecDeque<i64>) -> Option<i64> {
+pub(crate) fn find_first_not_sum(
+    previous: &mut VecDeque<i64>,
+    input: VecDeque<i64>,
+) -> Option<i64> {
     for i in input {
-        if !is_sum(&previous, i) {
+        if !is_sum(previous, i) {
             return Some(i);
         }
         previous.pop_front();
@@ -53,33 +35,33 @@ fn is_sum(previous: &VecDeque<i64>, n: i64) -> bool {
     false
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let input_file = open_input(&filename);
-    let mut inputs = read_input(input_file.unwrap())
-        .unwrap()
-        .into_iter()
-        .collect::<VecDeque<i64>>();
-
-    let preamble = 25;
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let mut inputs = read_input(reader)?.into_iter().collect::<VecDeque<i64>>();
+        if inputs.len() < PREAMBLE {
+            return Err(InputError::invalid("input shorter than preamble"));
+        }
 
-    let mut previous: VecDeque<i64> = inputs.drain(..preamble).collect();
+        let mut previous: VecDeque<i64> = inputs.drain(..PREAMBLE).collect();
 
-    println!("{:?}", find_first_not_sum(&mut previous, inputs));
+        find_first_not_sum(&mut previous, inputs)
+            .map(|n| n.to_string())
+            .ok_or_else(|| InputError::invalid("every number is a sum of the previous ones"))
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
     fn get_test_data() -> Vec<i64> {
-        return vec![
+        vec![
             35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
             576,
-        ];
+        ]
     }
 
     #[test]
@@ -1,34 +1,14 @@
+use crate::day09::part1::find_first_not_sum;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use std::collections::vec_deque::VecDeque;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
 
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<Vec<i64>, String> {
-    let reader = BufReader::new(reader);
+const PREAMBLE: usize = 25;
 
-    let mut lines: Vec<i64> = Vec::new();
-
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                lines.push(x.parse().unwrap());
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
-    }
-
-    Ok(lines)
+fn read_input(reader: impl Read) -> Result<Vec<i64>, InputError> {
+    input::read_parsed(reader)
 }
 
 // find position and length
@@ -37,7 +17,6 @@ fn find_contiguous_sum(previous: &VecDeque<i64>, n: i64) -> Option<(usize, usize
         let mut sum = 0;
         for (i2, p2) in previous.iter().skip(i).enumerate() {
             sum += p2;
-            //println!("[{}, {}] sum {}", i, i + i2 + 1, sum);
             if sum == n {
                 return Some((i, i2 + 1));
             }
@@ -55,7 +34,7 @@ fn find_sum_range(
     n: i64,
 ) -> Option<(usize, usize)> {
     for i in input {
-        if let Some(m) = find_contiguous_sum(&previous, n) {
+        if let Some(m) = find_contiguous_sum(previous, n) {
             return Some(m);
         }
         previous.pop_front();
@@ -67,32 +46,28 @@ fn find_sum_range(
 fn find_min_max_sum(previous: &mut VecDeque<i64>, input: VecDeque<i64>, n: i64) -> i64 {
     let (start, len) = find_sum_range(previous, input, n).unwrap();
 
-    println!("{:?}, {} {}", previous, start, len);
-
     let min = previous.iter().skip(start).take(len).min().unwrap();
     let max = previous.iter().skip(start).take(len).max().unwrap();
 
-    println!("{} {} {}", min, max, min + max);
-
     min + max
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Solver;
 
-    let filename = args.get(1).unwrap();
-
-    let input_file = open_input(&filename);
-    let mut inputs = read_input(input_file.unwrap())
-        .unwrap()
-        .into_iter()
-        .collect::<VecDeque<i64>>();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let mut inputs = read_input(reader)?.into_iter().collect::<VecDeque<i64>>();
+        if inputs.len() < PREAMBLE {
+            return Err(InputError::invalid("input shorter than preamble"));
+        }
 
-    let preamble = 25;
+        let mut previous: VecDeque<i64> = inputs.drain(..PREAMBLE).collect();
 
-    let mut previous: VecDeque<i64> = inputs.drain(..preamble).collect();
+        let invalid = find_first_not_sum(&mut previous.clone(), inputs.clone())
+            .ok_or_else(|| InputError::invalid("every number is a sum of the previous ones"))?;
 
-    println!("{}", find_min_max_sum(&mut previous, inputs, 507622668));
+        Ok(find_min_max_sum(&mut previous, inputs, invalid).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -100,10 +75,10 @@ mod tests {
     use super::*;
 
     fn get_test_data() -> Vec<i64> {
-        return vec![
+        vec![
             35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309,
             576,
-        ];
+        ]
     }
 
     #[test]
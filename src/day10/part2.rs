@@ -1,34 +1,11 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
 
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<Vec<i64>, String> {
-    let reader = BufReader::new(reader);
-
-    let mut lines: Vec<i64> = Vec::new();
-
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                lines.push(x.parse().unwrap());
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
-    }
-
-    Ok(lines)
+fn read_input(reader: impl Read) -> Result<Vec<i64>, InputError> {
+    input::read_parsed(reader)
 }
 
 fn get_arrangements(input: &[i64]) -> i64 {
@@ -42,25 +19,21 @@ fn get_arrangements(input: &[i64]) -> i64 {
         cache.insert(x, p1 + p2 + p3);
         cache
     });
-    println!("{:?} cache", cache);
 
     let max = cache.iter().max_by_key(|(&k, _)| k);
 
-    println!("{:?} max", max);
-
     *max.unwrap().1
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Solver;
 
-    let filename = args.get(1).unwrap();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let mut inputs = read_input(reader)?;
+        inputs.sort_unstable();
 
-    let input_file = open_input(&filename);
-    let mut inputs = read_input(input_file.unwrap()).unwrap();
-    inputs.sort_unstable();
-
-    println!("{}", get_arrangements(&inputs));
+        Ok(get_arrangements(&inputs).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -68,14 +41,14 @@ mod tests {
     use super::*;
 
     fn get_test_data() -> Vec<i64> {
-        return vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+        vec![16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4]
     }
 
     fn get_test_data_2() -> Vec<i64> {
-        return vec![
+        vec![
             28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35,
             8, 17, 7, 9, 4, 2, 34, 10, 3,
-        ];
+        ]
     }
 
     #[test]
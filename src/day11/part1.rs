@@ -0,0 +1,533 @@
+use crate::automaton::Automaton;
+use crate::automaton::Dense;
+use crate::automaton::Rule;
+use crate::input;
+use crate::input::InputError;
+use crate::render::Frame;
+use crate::render::Rgb;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::fmt;
+use std::io::Read;
+use std::str::FromStr;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub(crate) enum Cell {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl Cell {
+    fn from_char(c: char) -> Option<Cell> {
+        match c {
+            '.' => Some(Cell::Floor),
+            'L' => Some(Cell::Empty),
+            '#' => Some(Cell::Occupied),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn to_char(self) -> char {
+        match self {
+            Cell::Floor => '.',
+            Cell::Empty => 'L',
+            Cell::Occupied => '#',
+        }
+    }
+
+    pub(crate) fn is_seat(self) -> bool {
+        self != Cell::Floor
+    }
+}
+
+/// Colours of floor, empty and occupied seats when drawn.
+pub(crate) const PALETTE: [Rgb; 3] = [[48, 48, 48], [64, 160, 64], [208, 64, 64]];
+
+#[derive(PartialEq, Debug, Clone)]
+pub(crate) struct Seats {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    pub(crate) seats: Vec<Cell>,
+}
+
+const DIRECTIONS: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl Seats {
+    /// The cell at (x, y), None outside the layout.
+    pub(crate) fn get(&self, x: i64, y: i64) -> Option<Cell> {
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return None;
+        }
+        Some(self.seats[y as usize * self.width + x as usize])
+    }
+
+    pub(crate) fn occupied(&self) -> usize {
+        self.seats.iter().filter(|&&c| c == Cell::Occupied).count()
+    }
+
+    // first seat seen from (x, y) looking in direction (dx, dy), no further
+    // than `reach` positions away if given
+    fn first_seat(
+        &self,
+        (x, y): (i64, i64),
+        (dx, dy): (i64, i64),
+        reach: Option<usize>,
+    ) -> Option<(i64, i64)> {
+        let mut pos = (x + dx, y + dy);
+        let mut distance = 1;
+        while let Some(cell) = self.get(pos.0, pos.1) {
+            if reach.is_some_and(|reach| distance > reach) {
+                break;
+            }
+            if cell.is_seat() {
+                return Some(pos);
+            }
+            pos = (pos.0 + dx, pos.1 + dy);
+            distance += 1;
+        }
+        None
+    }
+
+    /// Position of every seat with whether it is occupied, floor is left out.
+    fn seat_cells(&self) -> Vec<((i64, i64), bool)> {
+        let mut cells = Vec::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell = self.seats[y * self.width + x];
+                if cell.is_seat() {
+                    cells.push(((x as i64, y as i64), cell == Cell::Occupied));
+                }
+            }
+        }
+        cells
+    }
+
+    #[cfg(test)]
+    pub(crate) fn print(&self) {
+        println!("{}", self);
+    }
+
+    /// The layout with one pixel per position, coloured as in `PALETTE`.
+    pub(crate) fn frame(&self) -> Frame {
+        let mut frame = Frame::new(self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                frame.set(x, y, self.seats[y * self.width + x] as u8);
+            }
+        }
+        frame
+    }
+}
+
+impl fmt::Display for Seats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.seats.chunks(self.width.max(1)) {
+            let line: String = row.iter().map(|c| c.to_char()).collect();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Which seats a seat looks at when deciding whether to change.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub(crate) enum NeighborPolicy {
+    /// The eight positions around it.
+    Adjacent,
+    /// The first seat in each of the eight directions, however far away.
+    LineOfSight,
+    /// The first seat in each of the eight directions, at most this many
+    /// positions away.
+    Radius(usize),
+}
+
+impl NeighborPolicy {
+    fn reach(self) -> Option<usize> {
+        match self {
+            NeighborPolicy::Adjacent => Some(1),
+            NeighborPolicy::LineOfSight => None,
+            NeighborPolicy::Radius(reach) => Some(reach),
+        }
+    }
+
+    /// The seats the seat at (x, y) looks at.
+    pub(crate) fn neighbours(self, seats: &Seats, x: i64, y: i64) -> Vec<(i64, i64)> {
+        DIRECTIONS
+            .iter()
+            .filter_map(|&direction| seats.first_seat((x, y), direction, self.reach()))
+            .collect()
+    }
+}
+
+impl FromStr for NeighborPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "adjacent" => Ok(NeighborPolicy::Adjacent),
+            "sight" | "line-of-sight" => Ok(NeighborPolicy::LineOfSight),
+            _ => match s.parse() {
+                Ok(reach) if reach > 0 => Ok(NeighborPolicy::Radius(reach)),
+                _ => Err(String::from("expected adjacent, sight or a radius")),
+            },
+        }
+    }
+}
+
+/// Seats filling up and emptying with the neighbours of every seat looked up
+/// once up front.
+pub(crate) struct Simulation {
+    layout: Seats,
+    automaton: Dense<(i64, i64)>,
+}
+
+impl Simulation {
+    /// An empty seat is taken when none of its neighbours are occupied, and
+    /// left when `tolerance` or more are.
+    pub(crate) fn new(layout: Seats, policy: NeighborPolicy, tolerance: usize) -> Self {
+        let automaton = Dense::new(
+            layout.seat_cells(),
+            |&(x, y)| policy.neighbours(&layout, x, y),
+            Rule::new(vec![0], 0..tolerance),
+        );
+
+        Simulation { layout, automaton }
+    }
+
+    /// Advances one round and returns whether anyone moved.
+    pub(crate) fn step(&mut self) -> bool {
+        self.automaton.step()
+    }
+
+    /// The current state of the layout.
+    pub(crate) fn seats(&self) -> Seats {
+        let mut seats = self.layout.clone();
+        for (&(x, y), occupied) in self.automaton.iter() {
+            seats.seats[y as usize * seats.width + x as usize] = if occupied {
+                Cell::Occupied
+            } else {
+                Cell::Empty
+            };
+        }
+        seats
+    }
+
+    /// Runs until nobody moves and returns the number of rounds where
+    /// someone did, with the final seats.
+    pub(crate) fn run_until_stable(&mut self) -> (usize, Seats) {
+        let generations = self.automaton.run_until_stable();
+        (generations, self.seats())
+    }
+}
+
+pub(crate) fn read_input(reader: impl Read) -> Result<Seats, InputError> {
+    let mut seats = Vec::new();
+    let mut width = None;
+    let mut height = 0;
+    for (i, line) in input::read_lines(reader)?.iter().enumerate() {
+        let row = line
+            .chars()
+            .map(|c| {
+                Cell::from_char(c)
+                    .ok_or_else(|| InputError::parse(i + 1, format!("invalid seat {:?}", c)))
+            })
+            .collect::<Result<Vec<Cell>, InputError>>()?;
+
+        match width {
+            None => width = Some(row.len()),
+            Some(width) if width != row.len() => {
+                return Err(InputError::parse(
+                    i + 1,
+                    format!("expected {} positions, got {}", width, row.len()),
+                ))
+            }
+            Some(_) => {}
+        }
+        seats.extend(row);
+        height += 1;
+    }
+
+    Ok(Seats {
+        width: width.unwrap_or(0),
+        height,
+        seats,
+    })
+}
+
+/// Number of occupied seats once nobody moves, with `--neighbours` and
+/// `--tolerance` overriding the puzzle's rules.
+pub(crate) fn occupied_when_stable(
+    reader: &mut dyn Read,
+    options: &Options,
+    policy: NeighborPolicy,
+    tolerance: usize,
+) -> Result<String, InputError> {
+    let policy = options.parse_or("neighbours", policy)?;
+    let tolerance = options.parse_or("tolerance", tolerance)?;
+
+    let mut simulation = Simulation::new(read_input(reader)?, policy, tolerance);
+    let (_, seats) = simulation.run_until_stable();
+
+    Ok(seats.occupied().to_string())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        occupied_when_stable(reader, options, NeighborPolicy::Adjacent, 4)
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["neighbours", "tolerance"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply_rules(seats: &Seats) -> Seats {
+        let mut simulation = Simulation::new(seats.clone(), NeighborPolicy::Adjacent, 4);
+        simulation.step();
+        simulation.seats()
+    }
+
+    fn get_test_data() -> String {
+        String::from(
+            "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL",
+        )
+    }
+
+    #[test]
+    fn test_read_input() {
+        use Cell::{Empty as L, Floor as F};
+
+        let map = read_input(get_test_data().as_bytes()).unwrap();
+
+        let expected = Seats {
+            width: 10,
+            height: 10,
+            seats: vec![
+                L, F, L, L, F, L, L, F, L, L, L, L, L, L, L, L, L, F, L, L, L, F, L, F, L, F, F, L,
+                F, F, L, L, L, L, F, L, L, F, L, L, L, F, L, L, F, L, L, F, L, L, L, F, L, L, L, L,
+                L, F, L, L, F, F, L, F, L, F, F, F, F, F, L, L, L, L, L, L, L, L, L, L, L, F, L, L,
+                L, L, L, L, F, L, L, F, L, L, L, L, L, F, L, L,
+            ],
+        };
+
+        assert_eq!(map, expected);
+
+        map.print();
+    }
+
+    #[test]
+    fn test_get() {
+        let init_map = read_input(
+            String::from(
+                ".#
+L#",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(init_map.get(-1, -1), None);
+        assert_eq!(init_map.get(-1, 0), None);
+        assert_eq!(init_map.get(0, -1), None);
+
+        assert_eq!(init_map.get(0, 0), Some(Cell::Floor));
+        assert_eq!(init_map.get(1, 0), Some(Cell::Occupied));
+        assert_eq!(init_map.get(0, 1), Some(Cell::Empty));
+        assert_eq!(init_map.get(1, 1), Some(Cell::Occupied));
+
+        assert_eq!(init_map.get(init_map.width as i64, 0), None);
+        assert_eq!(
+            init_map.get(init_map.width as i64, init_map.height as i64),
+            None
+        );
+        assert_eq!(
+            init_map.get(init_map.width as i64, init_map.height as i64),
+            None
+        );
+    }
+
+    #[test]
+    fn test_apply_rules() {
+        let init_map = read_input(get_test_data().as_bytes()).unwrap();
+        init_map.print();
+
+        let expected_1 = read_input(
+            String::from(
+                "#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_1 = apply_rules(&init_map);
+        actual_1.print();
+        assert_eq!(expected_1, actual_1);
+
+        let expected_2 = read_input(
+            String::from(
+                "#.LL.L#.##
+#LLLLLL.L#
+L.L.L..L..
+#LLL.LL.L#
+#.LL.LL.LL
+#.LLLL#.##
+..L.L.....
+#LLLLLLLL#
+#.LLLLLL.L
+#.#LLLL.##
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_2 = apply_rules(&actual_1);
+        actual_2.print();
+        assert_eq!(expected_2, actual_2);
+
+        let expected_3 = read_input(
+            String::from(
+                "#.##.L#.##
+#L###LL.L#
+L.#.#..#..
+#L##.##.L#
+#.##.LL.LL
+#.###L#.##
+..#.#.....
+#L######L#
+#.LL###L.L
+#.#L###.##
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_3 = apply_rules(&actual_2);
+        actual_3.print();
+        assert_eq!(expected_3, actual_3);
+
+        let expected_4 = read_input(
+            String::from(
+                "#.#L.L#.##
+#LLL#LL.L#
+L.L.L..#..
+#LLL.##.L#
+#.LL.LL.LL
+#.LL#L#.##
+..L.L.....
+#L#LLLL#L#
+#.LLLLLL.L
+#.#L#L#.##
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_4 = apply_rules(&actual_3);
+        actual_4.print();
+        assert_eq!(expected_4, actual_4);
+
+        let expected_5 = read_input(
+            String::from(
+                "#.#L.L#.##
+#LLL#LL.L#
+L.#.L..#..
+#L##.##.L#
+#.#L.LL.LL
+#.#L#L#.##
+..L.L.....
+#L#L##L#L#
+#.LLLLLL.L
+#.#L#L#.##
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_5 = apply_rules(&actual_4);
+        actual_5.print();
+        assert_eq!(expected_5, actual_5);
+
+        assert_eq!(actual_5.occupied(), 37);
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        let layout = read_input(get_test_data().as_bytes()).unwrap();
+        let mut simulation = Simulation::new(layout, NeighborPolicy::Adjacent, 4);
+
+        let (generations, seats) = simulation.run_until_stable();
+        assert_eq!(generations, 5);
+        assert_eq!(seats.occupied(), 37);
+        assert!(!simulation.step());
+    }
+
+    #[test]
+    fn test_read_input_errors() {
+        match read_input("L.L\nL?L".as_bytes()) {
+            Err(InputError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {:?}", other),
+        }
+        match read_input("L.L\nLL".as_bytes()) {
+            Err(InputError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        assert_eq!("sight".parse(), Ok(NeighborPolicy::LineOfSight));
+        assert_eq!("3".parse(), Ok(NeighborPolicy::Radius(3)));
+        assert!("0".parse::<NeighborPolicy>().is_err());
+
+        let mut options = Options::new();
+        options.set("neighbours", "sight");
+        options.set("tolerance", "5");
+        let answer = Solver
+            .solve_with(&mut get_test_data().as_bytes(), &options)
+            .unwrap();
+        assert_eq!(answer, "26");
+    }
+}
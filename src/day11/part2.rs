@@ -0,0 +1,330 @@
+use crate::day11::part1::occupied_when_stable;
+use crate::day11::part1::NeighborPolicy;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        occupied_when_stable(reader, options, NeighborPolicy::LineOfSight, 5)
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["neighbours", "tolerance"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day11::part1::read_input;
+    use crate::day11::part1::Cell;
+    use crate::day11::part1::Seats;
+    use crate::day11::part1::Simulation;
+
+    fn get_visible_occupied(seats: &Seats, policy: NeighborPolicy, x: i64, y: i64) -> usize {
+        policy
+            .neighbours(seats, x, y)
+            .iter()
+            .filter(|&&(x, y)| seats.get(x, y) == Some(Cell::Occupied))
+            .count()
+    }
+
+    fn apply_rules(seats: &Seats) -> Seats {
+        let mut simulation = Simulation::new(seats.clone(), NeighborPolicy::LineOfSight, 5);
+        simulation.step();
+        simulation.seats()
+    }
+
+    fn get_test_data() -> String {
+        String::from(
+            "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL",
+        )
+    }
+
+    #[test]
+    fn test_read_input() {
+        use Cell::{Empty as L, Floor as F};
+
+        let map = read_input(get_test_data().as_bytes()).unwrap();
+
+        let expected = Seats {
+            width: 10,
+            height: 10,
+            seats: vec![
+                L, F, L, L, F, L, L, F, L, L, L, L, L, L, L, L, L, F, L, L, L, F, L, F, L, F, F, L,
+                F, F, L, L, L, L, F, L, L, F, L, L, L, F, L, L, F, L, L, F, L, L, L, F, L, L, L, L,
+                L, F, L, L, F, F, L, F, L, F, F, F, F, F, L, L, L, L, L, L, L, L, L, L, L, F, L, L,
+                L, L, L, L, F, L, L, F, L, L, L, L, L, F, L, L,
+            ],
+        };
+
+        assert_eq!(map, expected);
+
+        map.print();
+    }
+
+    #[test]
+    fn test_is_occupied_1() {
+        let map1 = read_input(
+            String::from(
+                ".......#.
+...#.....
+.#.......
+.........
+..#L....#
+....#....
+.........
+#........
+...#.....",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            get_visible_occupied(&map1, NeighborPolicy::LineOfSight, 3, 4),
+            8
+        );
+    }
+
+    #[test]
+    fn test_is_occupied_2() {
+        let map2 = read_input(
+            String::from(
+                ".............
+.L.L.#.#.#.#.
+.............",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            get_visible_occupied(&map2, NeighborPolicy::LineOfSight, 1, 1),
+            0
+        );
+    }
+
+    #[test]
+    fn test_is_occupied_3() {
+        let map3 = read_input(
+            String::from(
+                ".##.##.
+#.#.#.#
+##...##
+...L...
+##...##
+#.#.#.#
+.##.##.
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            get_visible_occupied(&map3, NeighborPolicy::LineOfSight, 3, 3),
+            0
+        );
+    }
+
+    #[test]
+    fn test_apply_rules() {
+        let init_map = read_input(get_test_data().as_bytes()).unwrap();
+        init_map.print();
+
+        let expected_1 = read_input(
+            String::from(
+                "#.##.##.##
+#######.##
+#.#.#..#..
+####.##.##
+#.##.##.##
+#.#####.##
+..#.#.....
+##########
+#.######.#
+#.#####.##
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_1 = apply_rules(&init_map);
+        actual_1.print();
+        assert_eq!(expected_1, actual_1);
+
+        let expected_2 = read_input(
+            String::from(
+                "#.LL.LL.L#
+#LLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLLL.L
+#.LLLLL.L#
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_2 = apply_rules(&actual_1);
+        actual_2.print();
+        assert_eq!(expected_2, actual_2);
+
+        let expected_3 = read_input(
+            String::from(
+                "#.L#.##.L#
+#L#####.LL
+L.#.#..#..
+##L#.##.##
+#.##.#L.##
+#.#####.#L
+..#.#.....
+LLL####LL#
+#.L#####.L
+#.L####.L#
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_3 = apply_rules(&actual_2);
+        actual_3.print();
+        assert_eq!(expected_3, actual_3);
+
+        let expected_4 = read_input(
+            String::from(
+                "#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##LL.LL.L#
+L.LL.LL.L#
+#.LLLLL.LL
+..L.L.....
+LLLLLLLLL#
+#.LLLLL#.L
+#.L#LL#.L#
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_4 = apply_rules(&actual_3);
+        actual_4.print();
+        assert_eq!(expected_4, actual_4);
+
+        let expected_5 = read_input(
+            String::from(
+                "#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##L#.#L.L#
+L.L#.#L.L#
+#.L####.LL
+..#.#.....
+LLL###LLL#
+#.LLLLL#.L
+#.L#LL#.L#
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_5 = apply_rules(&actual_4);
+        actual_5.print();
+        assert_eq!(expected_5, actual_5);
+
+        let expected_6 = read_input(
+            String::from(
+                "#.L#.L#.L#
+#LLLLLL.LL
+L.L.L..#..
+##L#.#L.L#
+L.L#.LL.L#
+#.LLLL#.LL
+..#.L.....
+LLL###LLL#
+#.LLLLL#.L
+#.L#LL#.L#
+",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        let actual_6 = apply_rules(&actual_5);
+        actual_6.print();
+        assert_eq!(expected_6, actual_6);
+
+        assert_eq!(actual_6.occupied(), 26);
+    }
+
+    #[test]
+    fn test_radius() {
+        let map = read_input(
+            String::from(
+                "#...#
+.....
+..L.#
+.....
+#.#..",
+            )
+            .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_visible_occupied(&map, NeighborPolicy::Adjacent, 2, 2),
+            0
+        );
+        assert_eq!(
+            get_visible_occupied(&map, NeighborPolicy::Radius(1), 2, 2),
+            0
+        );
+        assert_eq!(
+            get_visible_occupied(&map, NeighborPolicy::Radius(2), 2, 2),
+            5
+        );
+        assert_eq!(
+            get_visible_occupied(&map, NeighborPolicy::LineOfSight, 2, 2),
+            5
+        );
+        assert_eq!(
+            get_visible_occupied(&map, NeighborPolicy::Radius(1), 3, 3),
+            2
+        );
+    }
+
+    #[test]
+    fn test_run_until_stable() {
+        let layout = read_input(get_test_data().as_bytes()).unwrap();
+        let mut simulation = Simulation::new(layout, NeighborPolicy::LineOfSight, 5);
+
+        let (generations, seats) = simulation.run_until_stable();
+        assert_eq!(generations, 6);
+        assert_eq!(seats.occupied(), 26);
+    }
+}
@@ -1,32 +1,20 @@
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use std::io::Read;
-use std::path::Path;
-
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<Vec<(char, i64)>, String> {
-    let reader = BufReader::new(reader);
 
+fn read_input(reader: impl Read) -> Result<Vec<(char, i64)>, InputError> {
     let mut instructions: Vec<(char, i64)> = Vec::new();
 
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                let action = x.chars().next().unwrap();
-                let val = x[1..].parse().unwrap();
-                instructions.push((action, val));
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
+    for (i, x) in input::read_lines(reader)?.iter().enumerate() {
+        let action = x
+            .chars()
+            .next()
+            .ok_or_else(|| InputError::parse(i + 1, "empty instruction"))?;
+        let val = x[action.len_utf8()..]
+            .parse()
+            .map_err(|err| InputError::parse(i + 1, format!("invalid value {:?}: {}", x, err)))?;
+        instructions.push((action, val));
     }
 
     Ok(instructions)
@@ -82,25 +70,24 @@ impl Ship {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+pub struct Solver;
 
-    let filename = args.get(1).unwrap();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let instructions = read_input(reader)?;
 
-    let input_file = open_input(&filename);
-    let instructions = read_input(input_file.unwrap()).unwrap();
+        let mut ship = Ship {
+            dir: 1, // east,
+            xpos: 0,
+            ypos: 0,
+        };
 
-    let mut ship = Ship {
-        dir: 1, // east,
-        xpos: 0,
-        ypos: 0,
-    };
+        for i in instructions {
+            ship.execute(i.0, i.1);
+        }
 
-    for i in instructions {
-        ship.execute(i.0, i.1);
+        Ok(ship.manhattan().to_string())
     }
-
-    println!("{}", ship.manhattan());
 }
 
 #[cfg(test)]
@@ -0,0 +1,75 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::io::Read;
+
+fn read_input(reader: impl Read) -> Result<Vec<String>, InputError> {
+    input::read_lines(reader)
+}
+
+fn parse_lines(line: String) -> Vec<i64> {
+    line.split(',')
+        .filter(|&x| x != "x")
+        .map(|n| n.parse().unwrap())
+        .collect()
+}
+
+fn find_next_departures(arrival: i64, lines: Vec<i64>) -> Vec<(i64, i64)> {
+    lines
+        .iter()
+        .map(|line| (*line, ((arrival / line) + 1) * line))
+        .collect()
+}
+
+fn find_earliest(arrival: i64, next_deps: Vec<(i64, i64)>) -> (i64, i64) {
+    *next_deps.iter().min_by_key(|(_l, d)| d - arrival).unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let inputs = read_input(reader)?;
+        if inputs.len() < 2 {
+            return Err(InputError::invalid("expected arrival time and bus lines"));
+        }
+
+        let arrival: i64 = inputs[0]
+            .parse()
+            .map_err(|err| InputError::parse(1, format!("invalid arrival time: {}", err)))?;
+
+        let next_deps = find_next_departures(arrival, parse_lines(inputs[1].to_owned()));
+        let earliest = find_earliest(arrival, next_deps);
+
+        let wait_time = earliest.1 - arrival;
+
+        Ok((earliest.0 * wait_time).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let line = "7,13,x,x,59,x,31,19";
+        let expected: Vec<i64> = vec![7, 13, 59, 31, 19];
+        let actual = parse_lines(line.to_owned());
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_get_earliest_departure() {
+        let arrival = 939;
+        let lines: Vec<i64> = vec![7, 13, 59, 31, 19];
+        let expected: Vec<(i64, i64)> = vec![(7, 945), (13, 949), (59, 944), (31, 961), (19, 950)];
+        let next_deps = find_next_departures(arrival, lines);
+        assert_eq!(next_deps, expected);
+
+        let earliest = find_earliest(arrival, next_deps);
+
+        assert_eq!(earliest, (59, 944));
+    }
+}
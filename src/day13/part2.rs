@@ -1,33 +1,10 @@
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use std::io::Read;
-use std::path::Path;
 
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<Vec<String>, String> {
-    let reader = BufReader::new(reader);
-
-    let mut lines: Vec<String> = Vec::new();
-
-    for line_iter in reader.lines() {
-        match line_iter {
-            Ok(x) => {
-                lines.push(x.parse().unwrap());
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
-    }
-
-    Ok(lines)
+fn read_input(reader: impl Read) -> Result<Vec<String>, InputError> {
+    input::read_lines(reader)
 }
 
 fn parse_lines(line: String) -> Vec<(i64, i64)> {
@@ -46,46 +23,31 @@ fn find_earliest(lines: &[(i64, i64)]) -> i64 {
 
     // for following each increment & line
     for (i, x) in lines.iter().skip(1) {
-        println!(
-            "t = {}, starting search increment {} line {} step {}",
-            t, i, x, step
-        );
-
         // while current time + increment is not divisible by x
         while (t + i) % x != 0 {
             // step to next possible time
             t += step;
         }
 
-        println!(
-            "t = {}, was multiple for x {} i {}, next step {}",
-            t,
-            i,
-            x,
-            step * x
-        );
-
         // next time has to be at least multiple of previous ones
         step *= x;
     }
     t
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let input_file = open_input(&filename);
-    let inputs = read_input(input_file.unwrap()).unwrap();
-
-    let lines = parse_lines(inputs[1].to_owned());
-
-    println!("lines {:?}", lines);
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let inputs = read_input(reader)?;
+        if inputs.len() < 2 {
+            return Err(InputError::invalid("expected arrival time and bus lines"));
+        }
 
-    let t = find_earliest(&lines);
+        let lines = parse_lines(inputs[1].to_owned());
 
-    println!("t = {}", t);
+        Ok(find_earliest(&lines).to_string())
+    }
 }
 
 #[cfg(test)]
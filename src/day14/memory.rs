@@ -0,0 +1,178 @@
+/// A set of addresses given as a ternary mask: every bit in `floating` can be
+/// either 0 or 1, the other bits are the ones in `bits`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Pattern {
+    bits: u64,
+    floating: u64,
+}
+
+impl Pattern {
+    pub(crate) fn new(bits: u64, floating: u64) -> Self {
+        Pattern {
+            bits: bits & !floating,
+            floating,
+        }
+    }
+
+    pub(crate) fn single(address: u64) -> Self {
+        Pattern::new(address, 0)
+    }
+
+    /// Number of addresses in the set.
+    pub(crate) fn len(&self) -> u128 {
+        1 << self.floating.count_ones()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, address: u64) -> bool {
+        address & !self.floating == self.bits
+    }
+
+    fn intersects(&self, other: &Pattern) -> bool {
+        // bits fixed in both must agree
+        let fixed = !self.floating & !other.floating;
+        self.bits & fixed == other.bits & fixed
+    }
+
+    /// The addresses of `self` that are not in `other`, as disjoint patterns.
+    pub(crate) fn subtract(&self, other: &Pattern) -> Vec<Pattern> {
+        if !self.intersects(other) {
+            return vec![*self];
+        }
+
+        // peel off one floating bit of ours that the other has fixed at a
+        // time: the piece with the opposite value is outside `other`, the
+        // rest follows `other` on that bit and is split further
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        let mut split = self.floating & !other.floating;
+        while split != 0 {
+            let bit = split & split.wrapping_neg();
+            split &= !bit;
+
+            rest.floating &= !bit;
+            pieces.push(Pattern {
+                bits: rest.bits | (!other.bits & bit),
+                floating: rest.floating,
+            });
+            rest.bits |= other.bits & bit;
+        }
+
+        // what is left is entirely inside `other`
+        pieces
+    }
+}
+
+/// Memory written through address patterns. Written regions are kept
+/// disjoint, so overlapping writes are subtracted from earlier ones instead
+/// of expanding every address.
+#[derive(Debug, PartialEq, Default)]
+pub(crate) struct Memory {
+    regions: Vec<(Pattern, u64)>,
+}
+
+impl Memory {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn write(&mut self, addresses: Pattern, value: u64) {
+        let mut regions = Vec::with_capacity(self.regions.len() + 1);
+        for (region, old) in self.regions.drain(..) {
+            regions.extend(region.subtract(&addresses).into_iter().map(|p| (p, old)));
+        }
+        regions.push((addresses, value));
+
+        self.regions = regions;
+    }
+
+    /// The value at `address`, None if it has never been written.
+    #[cfg(test)]
+    pub(crate) fn get(&self, address: u64) -> Option<u64> {
+        self.regions
+            .iter()
+            .find(|(region, _)| region.contains(address))
+            .map(|&(_, value)| value)
+    }
+
+    /// Sum of the values at every written address.
+    pub(crate) fn sum(&self) -> u128 {
+        self.regions
+            .iter()
+            .map(|(region, value)| region.len() * *value as u128)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new(0b111010, 0b100001);
+
+        assert_eq!(pattern.len(), 4);
+        assert!(pattern.contains(26));
+        assert!(pattern.contains(27));
+        assert!(pattern.contains(58));
+        assert!(pattern.contains(59));
+        assert!(!pattern.contains(18));
+    }
+
+    #[test]
+    fn test_subtract() {
+        let all = Pattern::new(0, 0b1111);
+
+        let pieces = all.subtract(&Pattern::new(0b0100, 0b0011));
+        assert_eq!(pieces.iter().map(|p| p.len()).sum::<u128>(), 12);
+        for address in 0..16 {
+            let inside = pieces.iter().filter(|p| p.contains(address)).count();
+            let expected = if address & 0b1100 == 0b0100 { 0 } else { 1 };
+            assert_eq!(inside, expected, "address {}", address);
+        }
+
+        let disjoint = Pattern::new(0b1000, 0b0011);
+        assert_eq!(
+            disjoint.subtract(&Pattern::new(0b0100, 0b0011)),
+            vec![disjoint]
+        );
+        assert!(disjoint.subtract(&all).is_empty());
+    }
+
+    #[test]
+    fn test_memory() {
+        let mut mem = Memory::new();
+        mem.write(Pattern::new(0b111010, 0b100001), 100);
+        mem.write(Pattern::new(0b10000, 0b1011), 1);
+
+        assert_eq!(mem.get(26), Some(1));
+        assert_eq!(mem.get(58), Some(100));
+        assert_eq!(mem.get(0), None);
+        assert_eq!(mem.sum(), 208);
+    }
+
+    #[test]
+    fn test_memory_many_floating_bits() {
+        let mut mem = Memory::new();
+
+        // 34 floating bits, far too many addresses to expand
+        mem.write(Pattern::new(0, (1 << 34) - 1), 5);
+        assert_eq!(mem.sum(), 5 << 34);
+
+        // overwrite a quarter of them
+        mem.write(Pattern::new(0b11 << 32, (1 << 32) - 1), 7);
+        assert_eq!(mem.sum(), 5 * (3 << 32) + (7 << 32));
+
+        // and one single address in the middle of both
+        mem.write(Pattern::single((0b11 << 32) | 12345), 1);
+        mem.write(Pattern::single(12345), 0);
+        assert_eq!(mem.sum(), 5 * ((3 << 32) - 1) + 7 * ((1 << 32) - 1) + 1);
+
+        assert_eq!(mem.get(12345), Some(0));
+        assert_eq!(mem.get(12346), Some(5));
+        assert_eq!(mem.get((0b11 << 32) | 12345), Some(1));
+        assert_eq!(mem.get((0b11 << 32) | 12346), Some(7));
+        assert_eq!(mem.get(1 << 34), None);
+    }
+}
@@ -0,0 +1,267 @@
+use crate::day14::memory::Memory;
+use crate::day14::memory::Pattern;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+use std::str::FromStr;
+
+const WORD_BITS: u32 = 36;
+
+/// Bits forced to 0, forced to 1 and left floating by a bitmask.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub(crate) struct Mask {
+    pub(crate) zeros: u64,
+    pub(crate) ones: u64,
+    pub(crate) floating: u64,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Instruction {
+    Mask(Mask),
+    Mem { address: u64, value: u64 },
+}
+
+/// How the docking program applies its bitmask.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Decoder {
+    /// The mask overwrites bits of the written value.
+    V1,
+    /// The mask overwrites bits of the address and floating bits write to
+    /// every combination of them.
+    V2,
+}
+
+impl FromStr for Decoder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "v1" | "1" => Ok(Decoder::V1),
+            "v2" | "2" => Ok(Decoder::V2),
+            _ => Err(String::from("expected v1 or v2")),
+        }
+    }
+}
+
+fn parse_mask(mask: &str) -> Result<Mask, String> {
+    if mask.len() != WORD_BITS as usize {
+        return Err(format!("mask {:?} is not {} bits long", mask, WORD_BITS));
+    }
+
+    let mut result = Mask::default();
+    for c in mask.chars() {
+        result.zeros <<= 1;
+        result.ones <<= 1;
+        result.floating <<= 1;
+        match c {
+            '0' => result.zeros |= 1,
+            '1' => result.ones |= 1,
+            'X' => result.floating |= 1,
+            _ => return Err(format!("invalid mask bit {:?}", c)),
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_word(s: &str, what: &str) -> Result<u64, String> {
+    let word: u64 = s.parse().map_err(|_| format!("invalid {} {:?}", what, s))?;
+    if word >> WORD_BITS != 0 {
+        return Err(format!(
+            "{} {} does not fit in {} bits",
+            what, word, WORD_BITS
+        ));
+    }
+    Ok(word)
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, String> {
+    let (target, value) = line
+        .split_once(" = ")
+        .ok_or_else(|| format!("expected \"<target> = <value>\", got {:?}", line))?;
+
+    if target == "mask" {
+        return parse_mask(value).map(Instruction::Mask);
+    }
+
+    let address = target
+        .strip_prefix("mem[")
+        .and_then(|t| t.strip_suffix(']'))
+        .ok_or_else(|| format!("unknown target {:?}", target))?;
+
+    Ok(Instruction::Mem {
+        address: parse_word(address, "address")?,
+        value: parse_word(value, "value")?,
+    })
+}
+
+pub(crate) fn read_input(reader: impl Read) -> Result<Vec<Instruction>, InputError> {
+    let mut program = Vec::new();
+
+    for (i, line) in input::read_lines(reader)?.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        program.push(parse_instruction(line).map_err(|err| InputError::parse(i + 1, err))?);
+    }
+
+    Ok(program)
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq)]
+pub(crate) struct CPU {
+    decoder: Decoder,
+    mem: Memory,
+    pub(crate) mask: Mask,
+}
+
+impl CPU {
+    pub(crate) fn new(decoder: Decoder) -> Self {
+        CPU {
+            decoder,
+            mem: Memory::new(),
+            mask: Mask::default(),
+        }
+    }
+
+    fn set_mem(&mut self, address: u64, value: u64) {
+        match self.decoder {
+            Decoder::V1 => self.mem.write(
+                Pattern::single(address),
+                value & !self.mask.zeros | self.mask.ones,
+            ),
+            Decoder::V2 => self.mem.write(
+                Pattern::new(address | self.mask.ones, self.mask.floating),
+                value,
+            ),
+        }
+    }
+
+    pub(crate) fn execute(&mut self, instr: &Instruction) {
+        match *instr {
+            Instruction::Mask(mask) => self.mask = mask,
+            Instruction::Mem { address, value } => self.set_mem(address, value),
+        }
+    }
+
+    /// The value at `address`, None if it has never been written.
+    #[cfg(test)]
+    pub(crate) fn get(&self, address: u64) -> Option<u64> {
+        self.mem.get(address)
+    }
+
+    pub(crate) fn mem_sum(&self) -> u128 {
+        self.mem.sum()
+    }
+}
+
+/// Runs the program and returns the sum of all values left in memory.
+pub(crate) fn run(program: &[Instruction], decoder: Decoder) -> u128 {
+    let mut cpu = CPU::new(decoder);
+    for instr in program {
+        cpu.execute(instr);
+    }
+    cpu.mem_sum()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        let program = read_input(reader)?;
+        let decoder = options.parse_or("decoder", Decoder::V1)?;
+
+        Ok(run(&program, decoder).to_string())
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["decoder"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+        let inputs = read_input(input.as_bytes()).unwrap();
+
+        let mut cpu = CPU::new(Decoder::V1);
+
+        cpu.execute(&inputs[0]);
+        assert_eq!(cpu.mask.zeros, 0b0000010);
+        assert_eq!(cpu.mask.ones, 0b1000000);
+
+        cpu.execute(&inputs[1]);
+        assert_eq!(cpu.get(8).unwrap(), 73);
+
+        cpu.execute(&inputs[2]);
+        assert_eq!(cpu.get(7).unwrap(), 101);
+
+        cpu.execute(&inputs[3]);
+        assert_eq!(cpu.get(8).unwrap(), 64);
+
+        assert_eq!(cpu.mem_sum(), 165);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        match read_input("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX\nmem[8] = x".as_bytes()) {
+            Err(InputError::Parse { line, message }) => {
+                assert_eq!(line, 2);
+                assert_eq!(message, "invalid value \"x\"");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        assert!(parse_instruction("mask = 1X0").is_err());
+        assert!(parse_instruction("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX2").is_err());
+        assert!(parse_instruction("mem[68719476736] = 1").is_err());
+        assert!(parse_instruction("mem[8 = 1").is_err());
+        assert!(parse_instruction("reg[8] = 1").is_err());
+        assert_eq!(
+            parse_instruction("mem[68719476735] = 3"),
+            Ok(Instruction::Mem {
+                address: 68719476735,
+                value: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_decoders() {
+        let program = read_input(
+            "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        assert_eq!(run(&program, Decoder::V2), 208);
+        assert_eq!(run(&program, Decoder::V1), 51);
+
+        let mut options = Options::new();
+        options.set("decoder", "v2");
+        let answer = Solver
+            .solve_with(
+                &mut "mask = 000000000000000000000000000000X1001X\nmem[42] = 100".as_bytes(),
+                &options,
+            )
+            .unwrap();
+        assert_eq!(answer, "400");
+    }
+}
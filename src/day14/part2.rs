@@ -0,0 +1,92 @@
+use crate::day14::part1::read_input;
+use crate::day14::part1::run;
+use crate::day14::part1::Decoder;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        let program = read_input(reader)?;
+        let decoder = options.parse_or("decoder", Decoder::V2)?;
+
+        Ok(run(&program, decoder).to_string())
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["decoder"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day14::part1::CPU;
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+        let inputs = read_input(input.as_bytes()).unwrap();
+
+        let mut cpu = CPU::new(Decoder::V2);
+
+        cpu.execute(&inputs[0]);
+        assert_eq!(
+            cpu.mask.zeros,
+            0b1111_1111_1111_1111_1111_1111_1111_1100_1100
+        );
+        assert_eq!(cpu.mask.ones, 0b0001_0010);
+        assert_eq!(cpu.mask.floating, 0b0010_0001);
+
+        cpu.execute(&inputs[1]);
+
+        assert_eq!(cpu.get(26), Some(100));
+        assert_eq!(cpu.get(27), Some(100));
+        assert_eq!(cpu.get(58), Some(100));
+        assert_eq!(cpu.get(59), Some(100));
+
+        cpu.execute(&inputs[2]);
+
+        cpu.execute(&inputs[3]);
+
+        assert_eq!(cpu.get(16), Some(1));
+        assert_eq!(cpu.get(17), Some(1));
+        assert_eq!(cpu.get(18), Some(1));
+        assert_eq!(cpu.get(19), Some(1));
+        assert_eq!(cpu.get(24), Some(1));
+        assert_eq!(cpu.get(25), Some(1));
+        assert_eq!(cpu.get(26), Some(1));
+        assert_eq!(cpu.get(27), Some(1));
+
+        assert_eq!(cpu.mem_sum(), 208);
+    }
+
+    #[test]
+    fn test_many_floating_bits() {
+        let program = read_input(
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX10
+mem[0] = 3
+mask = 0000XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX
+mem[1] = 2"
+                .as_bytes(),
+        )
+        .unwrap();
+
+        // 2^34 addresses ending in 10 hold 3, then 2^32 addresses below
+        // 2^32 are written with 2, 2^30 of which end in 10
+        assert_eq!(
+            run(&program, Decoder::V2),
+            3 * ((1 << 34) - (1 << 30)) + 2 * (1 << 32)
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::fmt;
+use std::io::Read;
+
+const TURNS: u32 = 2020;
+
+// the game keeps the last turn of every number it can speak, so the turn
+// count and the starting numbers are capped to keep that table in memory
+const MAX_TURNS: u32 = 100_000_000;
+const MAX_NUMBER: u32 = 100_000_000;
+
+pub(crate) struct MemoryGame {
+    turn: u32,
+    turns: u32,
+    starting_numbers: Vec<u32>,
+    // last_seen[n] is the turn number n was last spoken on, not counting the
+    // previous turn, or 0 if it has not been spoken yet
+    last_seen: Vec<u32>,
+    previous: u32,
+}
+
+impl MemoryGame {
+    /// Starts a game that ends after `turns` numbers have been spoken.
+    pub(crate) fn new(starting_numbers: Vec<u32>, turns: u32) -> Result<Self, InputError> {
+        if turns > MAX_TURNS {
+            return Err(InputError::invalid(format!(
+                "{} turns, at most {} are supported",
+                turns, MAX_TURNS
+            )));
+        }
+
+        let max_start = starting_numbers.iter().copied().max().unwrap_or(0);
+        if max_start > MAX_NUMBER {
+            return Err(InputError::invalid(format!(
+                "starting number {} is larger than {}",
+                max_start, MAX_NUMBER
+            )));
+        }
+
+        // every number spoken after the starting numbers is smaller than the
+        // turn it is spoken on
+        let size = max_start
+            .checked_add(1)
+            .map(|n| n.max(turns) as usize)
+            .ok_or_else(|| InputError::invalid("too many numbers to remember"))?;
+
+        Ok(Self {
+            turn: 0,
+            turns,
+            starting_numbers,
+            last_seen: vec![0; size],
+            previous: 0,
+        })
+    }
+
+    /// The number spoken on turn `n`, counting from 1, or None if the game
+    /// ends before that turn or it has already been spoken.
+    pub(crate) fn nth_spoken(&mut self, n: u32) -> Option<u32> {
+        if n <= self.turn {
+            return None;
+        }
+
+        let mut spoken = None;
+        while self.turn < n {
+            spoken = Some(self.next()?);
+        }
+        spoken
+    }
+}
+
+// the last seen table is far too large to print
+impl fmt::Debug for MemoryGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryGame")
+            .field("turn", &self.turn)
+            .field("turns", &self.turns)
+            .field("starting_numbers", &self.starting_numbers)
+            .field("previous", &self.previous)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Iterator for MemoryGame {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.turn >= self.turns {
+            return None;
+        }
+        self.turn += 1;
+
+        let speaking = match self.starting_numbers.get(self.turn as usize - 1) {
+            Some(&n) => n,
+            None => match self.last_seen[self.previous as usize] {
+                0 => 0,
+                seen => self.turn - 1 - seen,
+            },
+        };
+
+        if self.turn > 1 {
+            self.last_seen[self.previous as usize] = self.turn - 1;
+        }
+        self.previous = speaking;
+
+        Some(speaking)
+    }
+}
+
+/// Parses comma separated starting numbers, e.g. "0,3,6".
+pub(crate) fn parse_numbers(numbers: &str) -> Result<Vec<u32>, InputError> {
+    numbers
+        .trim()
+        .split(',')
+        .map(|n| {
+            n.trim()
+                .parse()
+                .map_err(|_| InputError::invalid(format!("invalid starting number {:?}", n)))
+        })
+        .collect()
+}
+
+pub(crate) fn read_input(reader: impl Read) -> Result<Vec<u32>, InputError> {
+    let lines = input::read_lines(reader)?;
+
+    let (i, line) = lines
+        .iter()
+        .enumerate()
+        .find(|(_, l)| !l.trim().is_empty())
+        .ok_or_else(|| InputError::invalid("no starting numbers"))?;
+
+    parse_numbers(line).map_err(|err| InputError::parse(i + 1, err.to_string()))
+}
+
+/// Starting numbers from `--numbers` if given, otherwise from the input.
+pub(crate) fn starting_numbers(
+    reader: &mut dyn Read,
+    options: &Options,
+) -> Result<Vec<u32>, InputError> {
+    match options.get("numbers") {
+        Some(numbers) => parse_numbers(numbers),
+        None => read_input(reader),
+    }
+}
+
+/// Plays the game for a fixed number of turns, unless `--turns` is given.
+pub struct Solver {
+    turns: u32,
+}
+
+impl Solver {
+    pub(crate) const fn new(turns: u32) -> Self {
+        Solver { turns }
+    }
+}
+
+pub const SOLVER: Solver = Solver::new(TURNS);
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        let turns = options.parse_or("turns", self.turns)?;
+        let mut game = MemoryGame::new(starting_numbers(reader, options)?, turns)?;
+
+        game.nth_spoken(turns)
+            .map(|n| n.to_string())
+            .ok_or_else(|| InputError::invalid("no numbers spoken"))
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["numbers", "turns"]
+    }
+
+    fn needs_input(&self, options: &Options) -> bool {
+        !options.contains("numbers")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iterator() {
+        let starting_numbers: Vec<u32> = vec![0, 3, 6];
+
+        let mut game = MemoryGame::new(starting_numbers, TURNS).unwrap();
+
+        println!("{:?}", game);
+        // Turn 1: The 1st number spoken is a starting number, 0.
+        assert_eq!(game.next(), Some(0));
+
+        println!("{:?}", game);
+        // Turn 2: The 2nd number spoken is a starting number, 3.
+        assert_eq!(game.next(), Some(3));
+
+        println!("{:?}", game);
+        // Turn 3: The 3rd number spoken is a starting number, 6.
+        assert_eq!(game.next(), Some(6));
+
+        println!("{:?}", game);
+        // Turn 4: Now, consider the last number spoken, 6. Since that was the first time the number had been spoken, the 4th number spoken is 0.
+        assert_eq!(game.next(), Some(0));
+
+        println!("{:?}", game);
+        // Turn 5: Next, again consider the last number spoken, 0. Since it had been spoken before, the next number to speak is the difference between the turn number when it was last spoken (the previous turn, 4) and the turn number of the time it was most recently spoken before then (turn 1). Thus, the 5th number spoken is 4 - 1, 3.
+        assert_eq!(game.next(), Some(3));
+
+        println!("{:?}", game);
+        // Turn 6: The last number spoken, 3 had also been spoken before, most recently on turns 5 and 2. So, the 6th number spoken is 5 - 2, 3.
+        assert_eq!(game.next(), Some(3));
+
+        println!("{:?}", game);
+        // Turn 7: Since 3 was just spoken twice in a row, and the last two turns are 1 turn apart, the 7th number spoken is 1.
+        assert_eq!(game.next(), Some(1));
+
+        println!("{:?}", game);
+        // Turn 8: Since 1 is new, the 8th number spoken is 0.
+        assert_eq!(game.next(), Some(0));
+
+        println!("{:?}", game);
+        // Turn 9: 0 was last spoken on turns 8 and 4, so the 9th number spoken is the difference between them, 4.
+        assert_eq!(game.next(), Some(4));
+
+        println!("{:?}", game);
+        // Turn 10: 4 is new, so the 10th number spoken is 0.
+        assert_eq!(game.next(), Some(0));
+
+        assert_eq!(game.last(), Some(436));
+    }
+
+    #[test]
+    fn test_iterator_next_more() {
+        let game = MemoryGame::new(vec![1, 3, 2], TURNS).unwrap();
+        assert_eq!(game.last(), Some(1));
+
+        let game = MemoryGame::new(vec![2, 1, 3], TURNS).unwrap();
+        assert_eq!(game.last(), Some(10));
+
+        let game = MemoryGame::new(vec![1, 2, 3], TURNS).unwrap();
+        assert_eq!(game.last(), Some(27));
+
+        let game = MemoryGame::new(vec![2, 3, 1], TURNS).unwrap();
+        assert_eq!(game.last(), Some(78));
+
+        let game = MemoryGame::new(vec![3, 2, 1], TURNS).unwrap();
+        assert_eq!(game.last(), Some(438));
+
+        let game = MemoryGame::new(vec![3, 1, 2], TURNS).unwrap();
+        assert_eq!(game.last(), Some(1836));
+    }
+
+    #[test]
+    fn test_turns() {
+        let game = MemoryGame::new(vec![0, 3, 6], 10).unwrap();
+        assert_eq!(game.last(), Some(0));
+
+        let game = MemoryGame::new(vec![0, 3, 6], 9).unwrap();
+        assert_eq!(game.last(), Some(4));
+    }
+
+    #[test]
+    fn test_nth_spoken() {
+        let mut game = MemoryGame::new(vec![0, 3, 6], TURNS).unwrap();
+
+        assert_eq!(game.nth_spoken(4), Some(0));
+        assert_eq!(game.nth_spoken(4), None);
+        assert_eq!(game.nth_spoken(9), Some(4));
+        assert_eq!(game.nth_spoken(2020), Some(436));
+        assert_eq!(game.nth_spoken(2021), None);
+    }
+
+    #[test]
+    fn test_read_input() {
+        assert_eq!(read_input("\n0,3,6\n".as_bytes()).unwrap(), vec![0, 3, 6]);
+
+        match read_input("1,x,3".as_bytes()) {
+            Err(InputError::Parse { line, .. }) => assert_eq!(line, 1),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(read_input("".as_bytes()).is_err());
+        assert!(parse_numbers("1,-2").is_err());
+    }
+
+    #[test]
+    fn test_solve_with_options() {
+        let mut options = Options::new();
+        options.set("numbers", "0,3,6");
+        options.set("turns", "10");
+
+        let answer = SOLVER
+            .solve_with(&mut "1,2,3".as_bytes(), &options)
+            .unwrap();
+        assert_eq!(answer, "0");
+        assert!(!SOLVER.needs_input(&options));
+    }
+
+    #[test]
+    fn test_limits() {
+        let mut options = Options::new();
+        options.set("numbers", "4294967295");
+        options.set("turns", "5");
+        let err = SOLVER.solve_with(&mut "".as_bytes(), &options).unwrap_err();
+        assert!(matches!(err, InputError::Invalid(_)));
+
+        let mut options = Options::new();
+        options.set("numbers", "0,3,6");
+        options.set("turns", "4000000000");
+        let err = SOLVER.solve_with(&mut "".as_bytes(), &options).unwrap_err();
+        assert!(matches!(err, InputError::Invalid(_)));
+
+        assert!(MemoryGame::new(vec![MAX_NUMBER], MAX_TURNS + 1).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::day15::part1::Solver;
+
+const TURNS: u32 = 30_000_000;
+
+/// Part 2 plays the part 1 game for 30 million turns.
+pub const SOLVER: Solver = Solver::new(TURNS);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day15::part1::MemoryGame;
+
+    #[test]
+    #[ignore = "30 million turns per game is slow without optimizations"]
+    fn test_iterator_next_more() {
+        let game = MemoryGame::new(vec![0, 3, 6], TURNS).unwrap();
+        assert_eq!(game.last(), Some(175594));
+
+        let game = MemoryGame::new(vec![1, 3, 2], TURNS).unwrap();
+        assert_eq!(game.last(), Some(2578));
+
+        let game = MemoryGame::new(vec![2, 1, 3], TURNS).unwrap();
+        assert_eq!(game.last(), Some(3544142));
+
+        let game = MemoryGame::new(vec![1, 2, 3], TURNS).unwrap();
+        assert_eq!(game.last(), Some(261214));
+
+        let game = MemoryGame::new(vec![2, 3, 1], TURNS).unwrap();
+        assert_eq!(game.last(), Some(6895259));
+
+        let game = MemoryGame::new(vec![3, 2, 1], TURNS).unwrap();
+        assert_eq!(game.last(), Some(18));
+
+        let game = MemoryGame::new(vec![3, 1, 2], TURNS).unwrap();
+        assert_eq!(game.last(), Some(362));
+    }
+}
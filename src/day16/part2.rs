@@ -1,17 +1,9 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use regex::Regex;
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
-
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
 
 type Ticket = Vec<i64>;
 
@@ -26,8 +18,6 @@ fn parse_rule(rule: &str) -> Option<Rule> {
     let rule_re = Regex::new(r"^(.*): (\d+)-(\d+) or (\d+)-(\d+)$").unwrap();
     if let Some(rule_capt) = rule_re.captures(rule) {
         if rule_capt.len() == 6 {
-            println!("rule_capt {:?}", rule_capt);
-
             return Some(Rule {
                 name: rule_capt[1].to_owned(),
                 range1: (rule_capt[2].parse().unwrap(), rule_capt[3].parse().unwrap()),
@@ -40,82 +30,52 @@ fn parse_rule(rule: &str) -> Option<Rule> {
     None
 }
 
-fn read_input(reader: impl Read) -> Result<(Vec<Rule>, Ticket, Vec<Ticket>), String> {
-    let reader = BufReader::new(reader);
-
-    let mut rules: Vec<Rule> = Vec::new();
-
-    let mut lines_iter = reader.lines();
-
-    loop {
-        let line: io::Result<String> = lines_iter.next().unwrap();
-        match line {
-            Ok(x) => {
-                if x.is_empty() {
-                    break;
-                }
+fn parse_ticket(line: &str) -> Result<Ticket, InputError> {
+    line.split(',')
+        .map(|x| {
+            x.parse()
+                .map_err(|err| InputError::invalid(format!("invalid ticket {:?}: {}", line, err)))
+        })
+        .collect()
+}
 
-                let rule = parse_rule(&x).unwrap();
-                rules.push(rule);
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
+fn read_input(reader: impl Read) -> Result<(Vec<Rule>, Ticket, Vec<Ticket>), InputError> {
+    let groups = input::read_groups(reader)?;
+    if groups.len() != 3 {
+        return Err(InputError::invalid(format!(
+            "expected 3 sections, found {}",
+            groups.len()
+        )));
     }
 
-    let _x = lines_iter.next();
-
-    let mut own_tickets: Vec<Vec<i64>> = Vec::new();
-    loop {
-        let line: io::Result<String> = lines_iter.next().unwrap();
-        match line {
-            Ok(x) => {
-                if x.is_empty() {
-                    break;
-                }
-
-                own_tickets.push(x.split(',').map(|x| x.parse().unwrap()).collect());
-
-                println!("{:?}", x);
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
+    let mut rules: Vec<Rule> = Vec::new();
+    for x in groups[0].iter() {
+        let rule =
+            parse_rule(x).ok_or_else(|| InputError::invalid(format!("invalid rule {:?}", x)))?;
+        rules.push(rule);
     }
 
-    let _x = lines_iter.next();
-    let mut tickets: Vec<Vec<i64>> = Vec::new();
-    for line in lines_iter {
-        match line {
-            Ok(x) => {
-                if x.is_empty() {
-                    break;
-                }
+    let own_ticket = match groups[1].get(1) {
+        Some(x) => parse_ticket(x)?,
+        None => return Err(InputError::invalid("missing own ticket")),
+    };
 
-                tickets.push(x.split(',').map(|x| x.parse().unwrap()).collect());
-
-                println!("{:?}", x);
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
+    let mut tickets: Vec<Vec<i64>> = Vec::new();
+    for x in groups[2].iter().skip(1) {
+        tickets.push(parse_ticket(x)?);
     }
-    Ok((rules, own_tickets[0].to_vec(), tickets))
+
+    Ok((rules, own_ticket, tickets))
 }
 
 fn validate_ticket(ticket: &Ticket, rules: &[Rule]) -> Option<i64> {
     for &t in ticket {
         let mut valid_rules = 0;
         for r in rules {
-            //println!("comparing {:?} to {:?}", t, r);
             if (t >= r.range1.0 && t <= r.range1.1) || (t >= r.range2.0 && t <= r.range2.1) {
                 valid_rules += 1;
             }
         }
-        //println!("valid rules for {} {}", t, valid_rules);
         if valid_rules == 0 {
             return Some(t);
         }
@@ -140,8 +100,7 @@ fn find_rule_columns(rules: Vec<Rule>, tickets: Vec<&Ticket>) -> HashMap<String,
                 valid_columns.push(i);
             }
         }
-        println!("rule {:?} has valid columns {:?}", r, valid_columns);
-        valid_columns_per_rule.insert(&r, valid_columns);
+        valid_columns_per_rule.insert(r, valid_columns);
     });
 
     let mut rule_to_column: HashMap<String, usize> = HashMap::new();
@@ -152,7 +111,6 @@ fn find_rule_columns(rules: Vec<Rule>, tickets: Vec<&Ticket>) -> HashMap<String,
         .filter(|(_k, v)| !v.is_empty())
         .min_by_key(|(_k, v)| v.len())
     {
-        println!("smallest rule {:?}", smallest);
         let smallest_column = smallest.1[0];
 
         // and map that rule to column id
@@ -177,40 +135,29 @@ fn find_rule_columns(rules: Vec<Rule>, tickets: Vec<&Ticket>) -> HashMap<String,
     rule_to_column
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
-
-    let input_file = open_input(&filename).unwrap();
-
-    let (rules, my_ticket, mut tickets) = read_input(input_file).unwrap();
-
-    tickets.push(my_ticket.clone());
-
-    println!("tickets {:?}", tickets);
+pub struct Solver;
 
-    let validated_tickets: Vec<&Ticket> = tickets
-        .iter()
-        .filter(|t| validate_ticket(t, &rules).is_none())
-        .collect();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let (rules, my_ticket, mut tickets) = read_input(reader)?;
 
-    println!("validated_tickets {:?}", validated_tickets);
+        tickets.push(my_ticket.clone());
 
-    let mapping = find_rule_columns(rules, validated_tickets);
+        let validated_tickets: Vec<&Ticket> = tickets
+            .iter()
+            .filter(|t| validate_ticket(t, &rules).is_none())
+            .collect();
 
-    println!("my_ticket {:?}", my_ticket);
+        let mapping = find_rule_columns(rules, validated_tickets);
 
-    let answer: i64 = mapping
-        .iter()
-        .filter(|(k, _v)| k.starts_with("departure"))
-        .map(|(_k, &v)| {
-            println!("{:?} {:?}", _k, v);
-            my_ticket[v]
-        })
-        .product();
+        let answer: i64 = mapping
+            .iter()
+            .filter(|(k, _v)| k.starts_with("departure"))
+            .map(|(_k, &v)| my_ticket[v])
+            .product();
 
-    println!("answer {:?}", answer);
+        Ok(answer.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -270,7 +217,7 @@ nearby tickets:
 
         let validated_tickets: Vec<&Vec<i64>> = tickets
             .iter()
-            .filter(|t| validate_ticket(&t, &rules).is_none())
+            .filter(|t| validate_ticket(t, &rules).is_none())
             .collect();
 
         let mapping = find_rule_columns(rules, validated_tickets);
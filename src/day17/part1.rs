@@ -0,0 +1,433 @@
+use crate::automaton::Automaton;
+use crate::automaton::Rule;
+use crate::automaton::Sparse;
+use crate::input;
+use crate::input::InputError;
+use crate::render::Frame;
+use crate::render::Rgb;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+
+const CYCLES: usize = 6;
+
+pub(crate) type Pos<const D: usize> = [i64; D];
+
+fn neighbours<const D: usize>(cell: &Pos<D>) -> Vec<Pos<D>> {
+    let count = 3usize.pow(D as u32);
+    let mut neighbours = Vec::with_capacity(count - 1);
+    for i in 0..count {
+        let mut neighbour = *cell;
+        let mut digits = i;
+        for c in neighbour.iter_mut() {
+            *c += (digits % 3) as i64 - 1;
+            digits /= 3;
+        }
+        if neighbour != *cell {
+            neighbours.push(neighbour);
+        }
+    }
+    neighbours
+}
+
+/// Every axis past x and y starts out at 0, so the space stays symmetric
+/// under flipping the sign of any of them and under swapping them. Such
+/// cells are all represented by one with the extra coordinates made
+/// non-negative and sorted.
+fn canonical<const D: usize>(mut cell: Pos<D>) -> Pos<D> {
+    if D > 2 {
+        let extra = &mut cell[2..];
+        for c in extra.iter_mut() {
+            *c = c.abs();
+        }
+        extra.sort_unstable();
+    }
+    cell
+}
+
+fn canonical_neighbours<const D: usize>(cell: &Pos<D>) -> Vec<Pos<D>> {
+    // neighbours that map to the same cell are kept, each of them counts
+    neighbours(cell).into_iter().map(canonical).collect()
+}
+
+fn factorial(n: usize) -> usize {
+    (1..=n).product()
+}
+
+/// Number of cells that `cell` stands for when it is canonical.
+fn images<const D: usize>(cell: &Pos<D>) -> usize {
+    if D <= 2 {
+        return 1;
+    }
+    let extra = &cell[2..];
+    let signs = extra.iter().filter(|&&c| c != 0).count();
+    let orders = extra
+        .chunk_by(|a, b| a == b)
+        .fold(factorial(extra.len()), |n, run| n / factorial(run.len()));
+    orders << signs
+}
+
+// active cubes stay active with 2 or 3 active neighbours, inactive ones
+// become active with exactly 3
+fn rule() -> Rule {
+    Rule::new(vec![3], vec![2, 3])
+}
+
+/// Colours of inactive and active cubes and of the gaps between slices when
+/// drawn.
+pub(crate) const PALETTE: [Rgb; 3] = [[16, 16, 32], [96, 208, 240], [64, 64, 64]];
+
+/// Conway cubes in `D` dimensions, started from a slice in the x-y plane.
+#[derive(Clone)]
+pub(crate) struct Cubes<const D: usize> {
+    symmetric: bool,
+    cells: Sparse<Pos<D>, fn(&Pos<D>) -> Vec<Pos<D>>>,
+}
+
+impl<const D: usize> Cubes<D> {
+    /// Cubes with the given x-y cells active. Only one of every set of
+    /// symmetric cells is stepped.
+    pub(crate) fn new(plane: &[[i64; 2]]) -> Self {
+        Self::with_symmetry(plane, true)
+    }
+
+    pub(crate) fn with_symmetry(plane: &[[i64; 2]], symmetric: bool) -> Self {
+        assert!(D >= 2, "cubes need at least the x and y axes");
+
+        let active = plane.iter().map(|&[x, y]| {
+            let mut cell = [0; D];
+            cell[0] = x;
+            cell[1] = y;
+            cell
+        });
+        let cells = if symmetric {
+            Sparse::weighted(active, canonical_neighbours::<D> as _, images::<D>, rule())
+        } else {
+            Sparse::new(active, neighbours::<D> as _, rule())
+        };
+
+        Cubes { symmetric, cells }
+    }
+
+    /// Smallest and largest coordinate of any active cube on each axis, None
+    /// if there are none.
+    pub(crate) fn bounds(&self) -> Option<(Pos<D>, Pos<D>)> {
+        let mut cells = self.cells.live().iter();
+        let first = *cells.next()?;
+        let (mut min, mut max) = cells.fold((first, first), |(mut min, mut max), cell| {
+            for axis in 0..D {
+                min[axis] = min[axis].min(cell[axis]);
+                max[axis] = max[axis].max(cell[axis]);
+            }
+            (min, max)
+        });
+
+        if self.symmetric && D > 2 {
+            // the mirror images of the stored cells reach as far out on
+            // every extra axis as the furthest stored one does on any
+            let reach = max[2..].iter().copied().max().unwrap_or(0);
+            for axis in 2..D {
+                min[axis] = -reach;
+                max[axis] = reach;
+            }
+        }
+        Some((min, max))
+    }
+
+    /// Prints the x-y slice at the given coordinates on the other axes.
+    #[cfg(test)]
+    pub(crate) fn print(&self, extra: &[i64]) {
+        let (min, max) = match self.bounds() {
+            Some(bounds) => bounds,
+            None => return,
+        };
+
+        for y in min[1]..=max[1] {
+            for x in min[0]..=max[0] {
+                let mut cell = [0; D];
+                cell[0] = x;
+                cell[1] = y;
+                cell[2..].copy_from_slice(extra);
+                if self.is_alive(&cell) {
+                    print!("#")
+                } else {
+                    print!(".")
+                }
+            }
+            println!();
+        }
+        println!();
+    }
+
+    /// The x-y slices within the given bounds side by side, one column of
+    /// slices for every z and one row for every w, with a gap between them.
+    /// Axes past w are drawn at 0.
+    pub(crate) fn frame(&self, min: Pos<D>, max: Pos<D>) -> Frame {
+        let span = |axis: usize| {
+            if axis < D {
+                (min[axis], (max[axis] - min[axis] + 1) as usize)
+            } else {
+                (0, 1)
+            }
+        };
+        let (x0, width) = span(0);
+        let (y0, height) = span(1);
+        let (z0, columns) = span(2);
+        let (w0, rows) = span(3);
+
+        let mut frame = Frame::new(columns * (width + 1) - 1, rows * (height + 1) - 1);
+        for row in 0..rows {
+            for column in 0..columns {
+                let (left, top) = (column * (width + 1), row * (height + 1));
+                for y in 0..height {
+                    for x in 0..width {
+                        let mut cell = [0; D];
+                        cell[0] = x0 + x as i64;
+                        cell[1] = y0 + y as i64;
+                        if D > 2 {
+                            cell[2] = z0 + column as i64;
+                        }
+                        if D > 3 {
+                            cell[3] = w0 + row as i64;
+                        }
+                        frame.set(left + x, top + y, self.is_alive(&cell) as u8);
+                    }
+                }
+                if column + 1 < columns {
+                    for y in 0..height {
+                        frame.set(left + width, top + y, 2);
+                    }
+                }
+            }
+            if row + 1 < rows {
+                for x in 0..frame.width() {
+                    frame.set(x, row * (height + 1) + height, 2);
+                }
+            }
+        }
+        frame
+    }
+}
+
+impl<const D: usize> Automaton for Cubes<D> {
+    type Cell = Pos<D>;
+
+    fn step(&mut self) -> bool {
+        self.cells.step()
+    }
+
+    fn population(&self) -> usize {
+        self.cells.population()
+    }
+
+    fn is_alive(&self, cell: &Pos<D>) -> bool {
+        if self.symmetric {
+            self.cells.is_alive(&canonical(*cell))
+        } else {
+            self.cells.is_alive(cell)
+        }
+    }
+}
+
+/// The active cells in the input, centred on the origin.
+pub(crate) fn read_input(reader: impl Read) -> Result<Vec<[i64; 2]>, InputError> {
+    let mut active = Vec::new();
+
+    let lines = input::read_lines(reader)?;
+
+    let y_offset = -(lines.len() as i64) / 2;
+
+    for (y, line) in lines.iter().enumerate() {
+        let iy = y_offset + y as i64;
+        let x_offset = -(line.len() as i64) / 2;
+        for (x, c) in line.chars().enumerate() {
+            let ix = x_offset + x as i64;
+            match c {
+                '#' => active.push([ix, iy]),
+                '.' => {}
+                _ => return Err(InputError::parse(y + 1, format!("invalid cube {:?}", c))),
+            }
+        }
+    }
+
+    Ok(active)
+}
+
+fn run<const D: usize>(plane: &[[i64; 2]], cycles: usize) -> usize {
+    let mut cubes = Cubes::<D>::new(plane);
+    cubes.run(cycles);
+    cubes.population()
+}
+
+/// Number of active cubes after `cycles` in as many dimensions as asked for.
+pub(crate) fn active_after(
+    plane: &[[i64; 2]],
+    dimensions: usize,
+    cycles: usize,
+) -> Result<usize, InputError> {
+    Ok(match dimensions {
+        2 => run::<2>(plane, cycles),
+        3 => run::<3>(plane, cycles),
+        4 => run::<4>(plane, cycles),
+        5 => run::<5>(plane, cycles),
+        6 => run::<6>(plane, cycles),
+        7 => run::<7>(plane, cycles),
+        8 => run::<8>(plane, cycles),
+        _ => {
+            return Err(InputError::invalid(format!(
+                "--dimensions \"{}\": must be between 2 and 8",
+                dimensions
+            )))
+        }
+    })
+}
+
+pub(crate) fn solve_in(
+    reader: &mut dyn Read,
+    options: &Options,
+    dimensions: usize,
+) -> Result<String, InputError> {
+    let dimensions = options.parse_or("dimensions", dimensions)?;
+    let cycles = options.parse_or("cycles", CYCLES)?;
+
+    active_after(&read_input(reader)?, dimensions, cycles).map(|n| n.to_string())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        solve_in(reader, options, 3)
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["dimensions", "cycles"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_data() -> String {
+        String::from(
+            ".#.
+..#
+###",
+        )
+    }
+
+    #[test]
+    fn test_read_input() {
+        let plane = read_input(get_test_data().as_bytes()).unwrap();
+        let map = Cubes::<3>::new(&plane);
+
+        assert_eq!(map.population(), 5);
+
+        println!("{:?}", plane);
+
+        assert!(!map.is_alive(&[-1, -1, 0]));
+        assert!(map.is_alive(&[0, -1, 0]));
+        assert!(!map.is_alive(&[1, -1, 0]));
+
+        assert!(!map.is_alive(&[-1, 0, 0]));
+        assert!(!map.is_alive(&[0, 0, 0]));
+        assert!(map.is_alive(&[1, 0, 0]));
+
+        assert!(map.is_alive(&[-1, 1, 0]));
+        assert!(map.is_alive(&[0, 1, 0]));
+        assert!(map.is_alive(&[1, 1, 0]));
+
+        map.print(&[0]);
+
+        match read_input(".#.\n.x.".as_bytes()) {
+            Err(InputError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_apply_rules() {
+        let plane = read_input(get_test_data().as_bytes()).unwrap();
+        let mut map = Cubes::<3>::new(&plane);
+
+        map.step();
+        assert_eq!(map.population(), 11);
+        assert!(map.is_alive(&[-1, 0, -1]));
+        assert!(map.is_alive(&[-1, 0, 1]));
+        map.print(&[-1]);
+
+        for _ in 1..6 {
+            map.step();
+        }
+
+        assert_eq!(map.population(), 112);
+    }
+
+    fn assert_symmetry_holds<const D: usize>(cycles: usize) {
+        let plane = read_input(get_test_data().as_bytes()).unwrap();
+
+        let mut symmetric = Cubes::<D>::new(&plane);
+        let mut full = Cubes::<D>::with_symmetry(&plane, false);
+        for _ in 0..cycles {
+            symmetric.step();
+            full.step();
+
+            assert_eq!(symmetric.population(), full.population());
+            assert_eq!(symmetric.bounds(), full.bounds());
+        }
+    }
+
+    #[test]
+    fn test_symmetry() {
+        assert_symmetry_holds::<3>(6);
+        assert_symmetry_holds::<4>(4);
+        assert_symmetry_holds::<5>(2);
+    }
+
+    #[test]
+    fn test_bounds_grow() {
+        let plane = read_input(get_test_data().as_bytes()).unwrap();
+        let mut map = Cubes::<3>::new(&plane);
+
+        assert_eq!(map.bounds(), Some(([-1, -1, 0], [1, 1, 0])));
+        map.run(2);
+        assert_eq!(map.bounds(), Some(([-2, -1, -2], [2, 3, 2])));
+
+        // far enough out that packed coordinates would have collided
+        let mut far = Cubes::<4>::new(&[[1000, 0], [1001, 0], [1002, 0]]);
+        far.step();
+        assert!(far.is_alive(&[1001, 1, 1, -1]));
+        assert!(!far.is_alive(&[1001 - 1024, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_images() {
+        assert_eq!(images(&[0, 0, 0, 0]), 1);
+        assert_eq!(images(&[0, 0, 1]), 2);
+        assert_eq!(images(&[0, 0, 0, 1]), 4);
+        assert_eq!(images(&[0, 0, 1, 1]), 4);
+        assert_eq!(images(&[0, 0, 1, 2]), 8);
+        assert_eq!(images(&[0, 0, 0, 1, 2]), 24);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let plane = read_input(get_test_data().as_bytes()).unwrap();
+
+        assert_eq!(active_after(&plane, 3, 6).unwrap(), 112);
+        assert!(active_after(&plane, 1, 6).is_err());
+
+        let mut options = Options::new();
+        options.set("dimensions", "4");
+        options.set("cycles", "6");
+        let answer = Solver
+            .solve_with(&mut get_test_data().as_bytes(), &options)
+            .unwrap();
+        assert_eq!(answer, "848");
+    }
+}
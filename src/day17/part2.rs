@@ -0,0 +1,79 @@
+use crate::day17::part1::solve_in;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        solve_in(reader, options, 4)
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["dimensions", "cycles"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::automaton::Automaton;
+    use crate::day17::part1::read_input;
+    use crate::day17::part1::Cubes;
+
+    fn get_test_data() -> String {
+        String::from(
+            ".#.
+..#
+###",
+        )
+    }
+
+    #[test]
+    fn test_read_input() {
+        let plane = read_input(get_test_data().as_bytes()).unwrap();
+        let map = Cubes::<4>::new(&plane);
+
+        assert_eq!(map.population(), 5);
+
+        println!("{:?}", plane);
+
+        assert!(!map.is_alive(&[-1, -1, 0, 0]));
+        assert!(map.is_alive(&[0, -1, 0, 0]));
+        assert!(!map.is_alive(&[1, -1, 0, 0]));
+
+        assert!(!map.is_alive(&[-1, 0, 0, 0]));
+        assert!(!map.is_alive(&[0, 0, 0, 0]));
+        assert!(map.is_alive(&[1, 0, 0, 0]));
+
+        assert!(map.is_alive(&[-1, 1, 0, 0]));
+        assert!(map.is_alive(&[0, 1, 0, 0]));
+        assert!(map.is_alive(&[1, 1, 0, 0]));
+
+        map.print(&[0, 0]);
+    }
+
+    #[test]
+    fn test_apply_rules() {
+        let plane = read_input(get_test_data().as_bytes()).unwrap();
+        let mut map = Cubes::<4>::new(&plane);
+
+        for _ in 0..6 {
+            map.step();
+        }
+
+        assert_eq!(map.population(), 848);
+    }
+
+    #[test]
+    fn test_solve() {
+        let answer = Solver.solve(&mut get_test_data().as_bytes()).unwrap();
+        assert_eq!(answer, "848");
+    }
+}
@@ -0,0 +1,252 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+use std::str::FromStr;
+
+const BASE: u64 = 1_000_000_000;
+
+/// Signed integer of any size, kept as base 10^9 digits with the least
+/// significant first so that printing it is cheap.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BigInt {
+    negative: bool,
+    // no trailing zero digits, zero has none and is never negative
+    digits: Vec<u32>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut digits: Vec<u32>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        BigInt {
+            negative: negative && !digits.is_empty(),
+            digits,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+}
+
+fn compare_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let digit = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push((digit % BASE) as u32);
+        carry = digit / BASE;
+    }
+    if carry > 0 {
+        sum.push(carry as u32);
+    }
+    sum
+}
+
+// a - b where a is at least as large as b
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &digit) in a.iter().enumerate() {
+        let subtrahend = *b.get(i).unwrap_or(&0) as i64 + borrow;
+        let mut digit = digit as i64 - subtrahend;
+        borrow = 0;
+        if digit < 0 {
+            digit += BASE as i64;
+            borrow = 1;
+        }
+        difference.push(digit as u32);
+    }
+    difference
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        let mut magnitude = n.unsigned_abs();
+        let mut digits = Vec::new();
+        while magnitude > 0 {
+            digits.push((magnitude % BASE) as u32);
+            magnitude /= BASE;
+        }
+        BigInt::new(n < 0, digits)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = String;
+
+    /// Decimal digits with an optional leading minus sign.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, magnitude) = match s.strip_prefix('-') {
+            Some(magnitude) => (true, magnitude),
+            None => (false, s),
+        };
+        if magnitude.is_empty() || !magnitude.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("invalid number {:?}", s));
+        }
+
+        // nine decimal digits per digit, starting from the least significant
+        let bytes = magnitude.as_bytes();
+        let digits = bytes
+            .rchunks(9)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .fold(0, |digit, b| digit * 10 + (b - b'0') as u32)
+            })
+            .collect();
+        Ok(BigInt::new(negative, digits))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.digits)
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add_magnitude(&self.digits, &other.digits));
+        }
+
+        // the sign of the larger one wins
+        match compare_magnitude(&self.digits, &other.digits) {
+            Ordering::Less => {
+                BigInt::new(other.negative, sub_magnitude(&other.digits, &self.digits))
+            }
+            _ => BigInt::new(self.negative, sub_magnitude(&self.digits, &other.digits)),
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: BigInt) -> BigInt {
+        self + -other
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: BigInt) -> BigInt {
+        let mut product = vec![0u64; self.digits.len() + other.digits.len()];
+        for (i, &a) in self.digits.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.digits.iter().enumerate() {
+                let digit = product[i + j] + a as u64 * b as u64 + carry;
+                product[i + j] = digit % BASE;
+                carry = digit / BASE;
+            }
+            product[i + other.digits.len()] += carry;
+        }
+
+        BigInt::new(
+            self.negative != other.negative,
+            product.into_iter().map(|d| d as u32).collect(),
+        )
+    }
+}
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> BigInt {
+        iter.fold(BigInt::default(), |sum, n| sum + n)
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digits = self.digits.iter().rev();
+        match digits.next() {
+            None => write!(f, "0"),
+            Some(first) => {
+                if self.negative {
+                    write!(f, "-")?;
+                }
+                write!(f, "{}", first)?;
+                digits.try_for_each(|digit| write!(f, "{:09}", digit))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(n: i64) -> BigInt {
+        BigInt::from(n)
+    }
+
+    #[test]
+    fn test_small_numbers() {
+        for &a in &[0, 1, -1, 7, -123_456_789_012, 999_999_999, 1_000_000_000] {
+            for &b in &[0, 3, -3, 1_000_000_001, -987_654_321_987] {
+                assert_eq!((big(a) + big(b)).to_string(), (a + b).to_string());
+                assert_eq!((big(a) - big(b)).to_string(), (a - b).to_string());
+                assert_eq!(
+                    (big(a) * big(b)).to_string(),
+                    (a as i128 * b as i128).to_string()
+                );
+            }
+        }
+        assert!((big(5) - big(5)).is_zero());
+        assert_eq!((-big(0)).to_string(), "0");
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        assert_eq!(big(i64::MIN).to_string(), i64::MIN.to_string());
+
+        let max = big(i64::MAX);
+        assert_eq!(
+            (max.clone() * max.clone()).to_string(),
+            (i64::MAX as i128 * i64::MAX as i128).to_string()
+        );
+
+        let huge = max.clone() * max.clone() * max.clone();
+        assert_eq!(
+            huge.to_string(),
+            "784637716923335095224261902710254454442933591094742482943"
+        );
+        assert_eq!((huge.clone() - huge.clone()).to_string(), "0");
+        assert_eq!(
+            (big(1) - huge).to_string(),
+            "-784637716923335095224261902710254454442933591094742482942"
+        );
+
+        assert_eq!(max.to_string().parse(), Ok(max.clone()));
+        assert_eq!("-000123".parse(), Ok(big(-123)));
+        assert_eq!("-0".parse(), Ok(big(0)));
+        assert_eq!(
+            "1000000000000000000000"
+                .parse::<BigInt>()
+                .unwrap()
+                .to_string(),
+            "1000000000000000000000"
+        );
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+
+        let total: BigInt = vec![max.clone(), max, big(2)].into_iter().sum();
+        assert_eq!(total.to_string(), "18446744073709551616");
+    }
+}
@@ -0,0 +1,186 @@
+use crate::day18::expr;
+use crate::day18::expr::Expr;
+use crate::day18::expr::Precedence;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+
+const HELP: &str = "type an expression to evaluate it, or a command:
+    :ast [expression]      show how the expression, or the last one, was parsed
+    :mode [part1|part2|math]
+                           switch operator precedence, or show the current one
+    :help                  show this help
+    :quit                  leave the calculator";
+
+/// Evaluates expressions read line by line, without any limit on the size
+/// of the result.
+pub struct Calculator {
+    precedence: Precedence,
+    last: Option<Expr>,
+}
+
+impl Calculator {
+    pub fn new(precedence: Precedence) -> Self {
+        Calculator {
+            precedence,
+            last: None,
+        }
+    }
+
+    // parses the expression and shows the error with a caret if it is not
+    // valid
+    fn parse(&self, line: &str, output: &mut impl Write) -> io::Result<Option<Expr>> {
+        match expr::parse(line, &self.precedence) {
+            Ok(expr) => Ok(Some(expr)),
+            Err(err) => {
+                writeln!(output, "{}", err.diagnostic())?;
+                Ok(None)
+            }
+        }
+    }
+
+    fn evaluate(&mut self, line: &str, output: &mut impl Write) -> io::Result<()> {
+        if let Some(expr) = self.parse(line, output)? {
+            writeln!(output, "{}", expr.eval_big())?;
+            self.last = Some(expr);
+        }
+        Ok(())
+    }
+
+    fn ast(&mut self, arg: &str, output: &mut impl Write) -> io::Result<()> {
+        if !arg.is_empty() {
+            if let Some(expr) = self.parse(arg, output)? {
+                writeln!(output, "{}", expr.tree())?;
+                self.last = Some(expr);
+            }
+            return Ok(());
+        }
+
+        match &self.last {
+            Some(expr) => writeln!(output, "{}", expr.tree()),
+            None => writeln!(output, "nothing evaluated yet"),
+        }
+    }
+
+    fn mode(&mut self, arg: &str, output: &mut impl Write) -> io::Result<()> {
+        if arg.is_empty() {
+            return writeln!(output, "mode {}", self.precedence);
+        }
+
+        match arg.parse() {
+            Ok(precedence) => {
+                self.precedence = precedence;
+                writeln!(output, "mode {}", self.precedence)
+            }
+            Err(err) => writeln!(output, "invalid mode {:?}: {}", arg, err),
+        }
+    }
+
+    // runs one line, false when the calculator should quit
+    fn command(&mut self, line: &str, output: &mut impl Write) -> io::Result<bool> {
+        let line = line.trim();
+        let (command, arg) = match line.split_once(char::is_whitespace) {
+            Some((command, arg)) => (command, arg.trim()),
+            None => (line, ""),
+        };
+
+        match command {
+            "" => {}
+            ":ast" => self.ast(arg, output)?,
+            ":mode" => self.mode(arg, output)?,
+            ":help" => writeln!(output, "{}", HELP)?,
+            ":quit" | ":q" => return Ok(false),
+            _ if command.starts_with(':') => {
+                writeln!(output, "unknown command {:?}, try :help", command)?
+            }
+            _ => self.evaluate(line, output)?,
+        }
+        Ok(true)
+    }
+
+    /// Reads lines until `:quit` or the end of the input.
+    pub fn run(&mut self, input: impl BufRead, output: &mut impl Write) -> io::Result<()> {
+        let mut lines = input.lines();
+        loop {
+            write!(output, "(calc) ")?;
+            output.flush()?;
+
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+            if !self.command(&line, output)? {
+                break;
+            }
+        }
+        writeln!(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_lines(precedence: Precedence, lines: &str) -> String {
+        let mut calculator = Calculator::new(precedence);
+        let mut output = Vec::new();
+        calculator.run(lines.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let output = run_lines(
+            Precedence::EQUAL,
+            "1 + 2 * 3\n\n9223372036854775807 * 2\n1 + (2\n",
+        );
+        let expected = "(calc) 9
+(calc) (calc) 18446744073709551614
+(calc) column 5: unclosed '('
+    1 + (2
+        ^
+(calc) \n";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_mode() {
+        let output = run_lines(
+            Precedence::EQUAL,
+            ":mode\n1 + 2 * 3\n:mode part2\n2 * 3 + 4\n:mode math\n2 * 3 + 4\n:mode 3\n:quit\n1\n",
+        );
+        let expected = "(calc) mode part1
+(calc) 9
+(calc) mode part2
+(calc) 14
+(calc) mode math
+(calc) 10
+(calc) invalid mode \"3\": expected part1, part2 or math
+(calc) \n";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_ast() {
+        let output = run_lines(
+            Precedence::ADDITION_FIRST,
+            ":ast\n:ast 1 * 2 + 3\n4 - 5\n:ast\n:ast 1 +\n:tree\n",
+        );
+        let expected = "(calc) nothing evaluated yet
+(calc) *
+  1
+  +
+    2
+    3
+(calc) -1
+(calc) -
+  4
+  5
+(calc) column 4: expected a number, found end of line
+    1 +
+       ^
+(calc) unknown command \":tree\", try :help
+(calc) \n";
+        assert_eq!(output, expected);
+    }
+}
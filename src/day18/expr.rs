@@ -0,0 +1,609 @@
+use crate::day18::bigint::BigInt;
+use crate::input::InputError;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+        };
+        f.write_str(symbol)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Expr {
+    /// The decimal digits of a literal, which may be too large for 64 bits.
+    Number(String),
+    Neg(Box<Expr>),
+    Binary(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// The value in 64 bits, or the first operation that does not fit.
+    pub fn eval(&self) -> Result<i64, OverflowError> {
+        let overflow = || OverflowError {
+            expression: self.to_string(),
+        };
+        match self {
+            Expr::Number(digits) => digits.parse().map_err(|_| overflow()),
+            Expr::Neg(e) => e.eval()?.checked_neg().ok_or_else(overflow),
+            Expr::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.eval()?, rhs.eval()?);
+                match op {
+                    Op::Add => lhs.checked_add(rhs),
+                    Op::Sub => lhs.checked_sub(rhs),
+                    Op::Mul => lhs.checked_mul(rhs),
+                }
+                .ok_or_else(overflow)
+            }
+        }
+    }
+
+    /// The value without any limit on its size.
+    pub fn eval_big(&self) -> BigInt {
+        match self {
+            Expr::Number(digits) => digits.parse().expect("literal is not a number"),
+            Expr::Neg(e) => -e.eval_big(),
+            Expr::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (lhs.eval_big(), rhs.eval_big());
+                match op {
+                    Op::Add => lhs + rhs,
+                    Op::Sub => lhs - rhs,
+                    Op::Mul => lhs * rhs,
+                }
+            }
+        }
+    }
+}
+
+impl Expr {
+    /// One node per line, operands indented under their operator.
+    pub fn tree(&self) -> String {
+        let mut lines = Vec::new();
+        self.tree_lines(0, &mut lines);
+        lines.join("\n")
+    }
+
+    fn tree_lines(&self, depth: usize, lines: &mut Vec<String>) {
+        let indent = "  ".repeat(depth);
+        match self {
+            Expr::Number(n) => lines.push(format!("{}{}", indent, n)),
+            Expr::Neg(e) => {
+                lines.push(format!("{}neg", indent));
+                e.tree_lines(depth + 1, lines);
+            }
+            Expr::Binary(op, lhs, rhs) => {
+                lines.push(format!("{}{}", indent, op));
+                lhs.tree_lines(depth + 1, lines);
+                rhs.tree_lines(depth + 1, lines);
+            }
+        }
+    }
+}
+
+/// An operation whose result does not fit in 64 bits.
+#[derive(Debug, PartialEq, Clone)]
+pub struct OverflowError {
+    pub expression: String,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} overflows 64 bits", self.expression)
+    }
+}
+
+impl Error for OverflowError {}
+
+// fully parenthesised, so the grouping the parser chose is visible
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{}", n),
+            Expr::Neg(e) => write!(f, "-{}", e),
+            Expr::Binary(op, lhs, rhs) => write!(f, "({} {} {})", lhs, op, rhs),
+        }
+    }
+}
+
+/// How tightly each operator binds, higher binds tighter. Operators on the
+/// same level are evaluated left to right, unary minus binds tighter than
+/// all of them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Precedence {
+    pub add: u8,
+    pub sub: u8,
+    pub mul: u8,
+}
+
+impl Precedence {
+    /// Everything left to right, as in part 1.
+    pub const EQUAL: Precedence = Precedence {
+        add: 1,
+        sub: 1,
+        mul: 1,
+    };
+
+    /// Addition and subtraction before multiplication, as in part 2.
+    pub const ADDITION_FIRST: Precedence = Precedence {
+        add: 2,
+        sub: 2,
+        mul: 1,
+    };
+
+    /// Multiplication before addition and subtraction, as usual.
+    pub const MATH: Precedence = Precedence {
+        add: 1,
+        sub: 1,
+        mul: 2,
+    };
+
+    pub fn level(&self, op: Op) -> u8 {
+        match op {
+            Op::Add => self.add,
+            Op::Sub => self.sub,
+            Op::Mul => self.mul,
+        }
+    }
+
+    // left and right binding power, the right one is higher so that
+    // operators on the same level group to the left
+    fn binding(&self, op: Op) -> (u32, u32) {
+        let level = self.level(op) as u32;
+        (2 * level + 1, 2 * level + 2)
+    }
+
+    fn unary(&self) -> u32 {
+        2 * self.add.max(self.sub).max(self.mul) as u32 + 3
+    }
+}
+
+impl FromStr for Precedence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "part1" | "1" | "equal" => Ok(Precedence::EQUAL),
+            "part2" | "2" | "addition-first" => Ok(Precedence::ADDITION_FIRST),
+            "math" => Ok(Precedence::MATH),
+            _ => Err(String::from("expected part1, part2 or math")),
+        }
+    }
+}
+
+impl fmt::Display for Precedence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Precedence::EQUAL => f.write_str("part1"),
+            Precedence::ADDITION_FIRST => f.write_str("part2"),
+            Precedence::MATH => f.write_str("math"),
+            Precedence { add, sub, mul } => write!(f, "+ {}, - {}, * {}", add, sub, mul),
+        }
+    }
+}
+
+/// Why an expression could not be parsed. Line and column count from 1.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+    /// The line that failed to parse.
+    pub text: String,
+}
+
+impl ParseError {
+    fn new(text: &str, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line: 1,
+            column,
+            message: message.into(),
+            text: text.to_owned(),
+        }
+    }
+
+    /// The same error on another line of the input.
+    pub fn at_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+
+    /// The message followed by the offending line with a caret under the
+    /// column.
+    pub fn diagnostic(&self) -> String {
+        format!(
+            "column {}: {}\n    {}\n    {}^",
+            self.column,
+            self.message,
+            self.text,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for InputError {
+    fn from(err: ParseError) -> InputError {
+        InputError::parse(err.line, err.diagnostic())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Token {
+    Number(String),
+    Op(Op),
+    Open,
+    Close,
+    End,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Op(op) => write!(f, "'{}'", op),
+            Token::Open => f.write_str("'('"),
+            Token::Close => f.write_str("')'"),
+            Token::End => f.write_str("end of line"),
+        }
+    }
+}
+
+// tokens with the column they start at, always ending with Token::End
+fn tokenize(line: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = line.chars().collect();
+
+    let mut i = 0;
+    while i < chars.len() {
+        let column = i + 1;
+        let token = match chars[i] {
+            ' ' | '\t' => {
+                i += 1;
+                continue;
+            }
+            '0'..='9' => {
+                let start = i;
+                while i + 1 < chars.len() && chars[i + 1].is_ascii_digit() {
+                    i += 1;
+                }
+                Token::Number(chars[start..=i].iter().collect())
+            }
+            '+' => Token::Op(Op::Add),
+            '-' => Token::Op(Op::Sub),
+            '*' => Token::Op(Op::Mul),
+            '(' => Token::Open,
+            ')' => Token::Close,
+            c => return Err(ParseError::new(line, column, format!("unexpected {:?}", c))),
+        };
+        tokens.push((column, token));
+        i += 1;
+    }
+
+    tokens.push((chars.len() + 1, Token::End));
+    Ok(tokens)
+}
+
+// reports the first parenthesis without a partner
+fn check_parentheses(line: &str, tokens: &[(usize, Token)]) -> Result<(), ParseError> {
+    let mut open = Vec::new();
+    for &(column, ref token) in tokens {
+        match token {
+            Token::Open => open.push(column),
+            Token::Close if open.pop().is_none() => {
+                return Err(ParseError::new(line, column, "unmatched ')'"));
+            }
+            _ => {}
+        }
+    }
+
+    match open.pop() {
+        Some(column) => Err(ParseError::new(line, column, "unclosed '('")),
+        None => Ok(()),
+    }
+}
+
+// the parser recurses once per parenthesis or unary minus, and evaluating
+// recurses once per operation, so deeper expressions are rejected before
+// either overflows the stack
+const MAX_NESTING: usize = 256;
+const MAX_DEPTH: usize = 1000;
+
+struct Parser<'a> {
+    line: &'a str,
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+    precedence: &'a Precedence,
+    nesting: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> (usize, Token) {
+        self.tokens[self.next].clone()
+    }
+
+    fn advance(&mut self) -> (usize, Token) {
+        let token = self.peek();
+        if token.1 != Token::End {
+            self.next += 1;
+        }
+        token
+    }
+
+    fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, column, message)
+    }
+
+    // depth of the tree with an operation at the given column on top, counted
+    // in operations
+    fn deeper(&self, column: usize, depth: usize) -> Result<usize, ParseError> {
+        if depth == MAX_DEPTH {
+            return Err(self.error(column, format!("more than {} operations deep", MAX_DEPTH)));
+        }
+        Ok(depth + 1)
+    }
+
+    // parses with one more level of nesting
+    fn nested(&mut self, column: usize, min_binding: u32) -> Result<(Expr, usize), ParseError> {
+        if self.nesting == MAX_NESTING {
+            return Err(self.error(
+                column,
+                format!("nested more than {} levels deep", MAX_NESTING),
+            ));
+        }
+
+        self.nesting += 1;
+        let expr = self.expression(min_binding);
+        self.nesting -= 1;
+        expr
+    }
+
+    // an operand and the depth of its tree
+    fn operand(&mut self) -> Result<(Expr, usize), ParseError> {
+        match self.advance() {
+            (_, Token::Number(n)) => Ok((Expr::Number(n), 0)),
+            (column, Token::Op(Op::Sub)) => {
+                let (operand, depth) = self.nested(column, self.precedence.unary())?;
+                Ok((Expr::Neg(Box::new(operand)), self.deeper(column, depth)?))
+            }
+            (column, Token::Open) => {
+                let inner = self.nested(column, 0)?;
+                match self.advance() {
+                    (_, Token::Close) => Ok(inner),
+                    (column, token) => {
+                        Err(self.error(column, format!("expected ')', found {}", token)))
+                    }
+                }
+            }
+            (column, token) => {
+                Err(self.error(column, format!("expected a number, found {}", token)))
+            }
+        }
+    }
+
+    // an expression and the depth of its tree
+    fn expression(&mut self, min_binding: u32) -> Result<(Expr, usize), ParseError> {
+        let (mut lhs, mut depth) = self.operand()?;
+
+        while let (column, Token::Op(op)) = self.peek() {
+            let (left, right) = self.precedence.binding(op);
+            if left < min_binding {
+                break;
+            }
+            self.advance();
+
+            let (rhs, rhs_depth) = self.expression(right)?;
+            depth = self.deeper(column, depth.max(rhs_depth))?;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok((lhs, depth))
+    }
+}
+
+/// Parses one expression with the given operator precedence. Errors are
+/// reported on line 1, see `ParseError::at_line`.
+pub fn parse(line: &str, precedence: &Precedence) -> Result<Expr, ParseError> {
+    let tokens = tokenize(line)?;
+    check_parentheses(line, &tokens)?;
+
+    let mut parser = Parser {
+        line,
+        tokens,
+        next: 0,
+        precedence,
+        nesting: 0,
+    };
+
+    let (expr, _) = parser.expression(0)?;
+    match parser.peek() {
+        (_, Token::End) => Ok(expr),
+        (column, token) => Err(ParseError::new(
+            line,
+            column,
+            format!("expected an operator, found {}", token),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(line: &str, precedence: &Precedence) -> i64 {
+        parse(line, precedence).unwrap().eval().unwrap()
+    }
+
+    #[test]
+    fn test_precedence() {
+        let line = "1 + 2 * 3 + 4 * 5 + 6";
+
+        assert_eq!(eval(line, &Precedence::EQUAL), 71);
+        assert_eq!(eval(line, &Precedence::ADDITION_FIRST), 231);
+        assert_eq!(eval(line, &Precedence::MATH), 33);
+
+        let custom = Precedence {
+            add: 3,
+            sub: 1,
+            mul: 2,
+        };
+        assert_eq!(eval("2 * 3 + 4 - 1 * 2", &custom), 12);
+    }
+
+    #[test]
+    fn test_ast() {
+        let expr = parse("2 * 3 + (4 * 5)", &Precedence::ADDITION_FIRST).unwrap();
+        assert_eq!(expr.to_string(), "(2 * (3 + (4 * 5)))");
+
+        let expr = parse("10 - 4 - 3", &Precedence::EQUAL).unwrap();
+        assert_eq!(expr.to_string(), "((10 - 4) - 3)");
+        assert_eq!(expr.eval(), Ok(3));
+    }
+
+    #[test]
+    fn test_tree() {
+        let expr = parse("2 * -(3 + 4)", &Precedence::EQUAL).unwrap();
+        assert_eq!(expr.tree(), "*\n  2\n  neg\n    +\n      3\n      4");
+        assert_eq!(Expr::Number(String::from("5")).tree(), "5");
+    }
+
+    #[test]
+    fn test_precedence_names() {
+        for precedence in [
+            Precedence::EQUAL,
+            Precedence::ADDITION_FIRST,
+            Precedence::MATH,
+        ] {
+            assert_eq!(precedence.to_string().parse(), Ok(precedence));
+        }
+        assert_eq!("2".parse(), Ok(Precedence::ADDITION_FIRST));
+        assert!("part3".parse::<Precedence>().is_err());
+
+        let custom = Precedence {
+            add: 3,
+            sub: 1,
+            mul: 2,
+        };
+        assert_eq!(custom.to_string(), "+ 3, - 1, * 2");
+    }
+
+    #[test]
+    fn test_numbers_and_minus() {
+        assert_eq!(eval("12 + 3", &Precedence::EQUAL), 15);
+        assert_eq!(eval("120*3", &Precedence::EQUAL), 360);
+        assert_eq!(eval("-2 * 3", &Precedence::ADDITION_FIRST), -6);
+        assert_eq!(eval("4 - -2", &Precedence::EQUAL), 6);
+        assert_eq!(eval("--3", &Precedence::EQUAL), 3);
+        assert_eq!(eval("-(1 + 2) * 2", &Precedence::EQUAL), -6);
+        assert_eq!(eval("2 * 3 - 1", &Precedence::ADDITION_FIRST), 4);
+        assert_eq!(
+            parse("-2 + 1", &Precedence::MATH).unwrap().to_string(),
+            "(-2 + 1)"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |line| parse(line, &Precedence::EQUAL).unwrap_err();
+
+        assert_eq!(error("1 + x").column, 5);
+        assert_eq!(
+            error("1 +").to_string(),
+            "line 1, column 4: expected a number, found end of line"
+        );
+        assert_eq!(error("1 2").column, 3);
+        assert_eq!(error("()").message, "expected a number, found ')'");
+
+        let err = error("(1 + 2").at_line(7);
+        assert_eq!(err.to_string(), "line 7, column 1: unclosed '('");
+        assert_eq!(
+            err.diagnostic(),
+            "column 1: unclosed '('\n    (1 + 2\n    ^"
+        );
+        assert_eq!(error("((1) + (2 * 3)").column, 1);
+        assert_eq!(error("(1) + ((2) * 3").column, 7);
+
+        let err = error("1 + 2) * (3");
+        assert_eq!(err.message, "unmatched ')'");
+        assert_eq!(
+            err.diagnostic(),
+            "column 6: unmatched ')'\n    1 + 2) * (3\n         ^"
+        );
+    }
+
+    #[test]
+    fn test_nesting() {
+        let line = format!("{}1{}", "(".repeat(256), ")".repeat(256));
+        assert_eq!(eval(&line, &Precedence::EQUAL), 1);
+
+        let line = format!("2 * {}1{}", "(".repeat(200_000), ")".repeat(200_000));
+        let err = parse(&line, &Precedence::EQUAL).unwrap_err();
+        assert_eq!(err.column, 261);
+        assert_eq!(err.message, "nested more than 256 levels deep");
+        assert!(err.diagnostic().ends_with(&format!("{}^", " ".repeat(260))));
+
+        let line = format!("{}1", "-".repeat(200_000));
+        assert_eq!(parse(&line, &Precedence::EQUAL).unwrap_err().column, 257);
+
+        let line = vec!["1"; 1002].join(" + ");
+        assert_eq!(eval(&line[4..], &Precedence::EQUAL), 1001);
+        let err = parse(&line, &Precedence::EQUAL).unwrap_err();
+        assert_eq!(err.column, 4003);
+        assert_eq!(err.message, "more than 1000 operations deep");
+    }
+
+    #[test]
+    fn test_wide_literals() {
+        let expr = parse("99999999999999999999 + 1", &Precedence::EQUAL).unwrap();
+        assert_eq!(expr.to_string(), "(99999999999999999999 + 1)");
+        assert_eq!(
+            expr.eval().unwrap_err().to_string(),
+            "99999999999999999999 overflows 64 bits"
+        );
+        assert_eq!(expr.eval_big().to_string(), "100000000000000000000");
+
+        let expr = parse("-9223372036854775808 * 0001", &Precedence::EQUAL).unwrap();
+        assert!(expr.eval().is_err());
+        assert_eq!(expr.eval_big().to_string(), "-9223372036854775808");
+    }
+
+    #[test]
+    fn test_overflow() {
+        let expr = parse("4611686018427387904 * 2 + 1", &Precedence::EQUAL).unwrap();
+        assert_eq!(
+            expr.eval().unwrap_err().to_string(),
+            "(4611686018427387904 * 2) overflows 64 bits"
+        );
+        assert_eq!(expr.eval_big().to_string(), "9223372036854775809");
+
+        let expr = parse("-9223372036854775807 - 1", &Precedence::EQUAL).unwrap();
+        assert_eq!(expr.eval(), Ok(i64::MIN));
+        assert!(Expr::Neg(Box::new(expr.clone())).eval().is_err());
+        assert_eq!(
+            Expr::Neg(Box::new(expr)).eval_big().to_string(),
+            "9223372036854775808"
+        );
+    }
+}
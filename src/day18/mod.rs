@@ -0,0 +1,5 @@
+pub mod bigint;
+pub mod calc;
+pub mod expr;
+pub mod part1;
+pub mod part2;
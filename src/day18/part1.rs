@@ -0,0 +1,185 @@
+use crate::day18::bigint::BigInt;
+use crate::day18::expr;
+use crate::day18::expr::Expr;
+use crate::day18::expr::Precedence;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+use std::str::FromStr;
+
+/// How the expressions are evaluated.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Arithmetic {
+    /// 64 bit integers, overflow is an error.
+    Checked,
+    /// Integers of any size.
+    Big,
+}
+
+impl FromStr for Arithmetic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "checked" => Ok(Arithmetic::Checked),
+            "big" => Ok(Arithmetic::Big),
+            _ => Err(String::from("expected checked or big")),
+        }
+    }
+}
+
+/// Parses every non-blank line of the input with the given precedence,
+/// keeping the line numbers.
+pub(crate) fn read_input(
+    reader: impl Read,
+    precedence: &Precedence,
+) -> Result<Vec<(usize, Expr)>, InputError> {
+    let mut expressions = Vec::new();
+
+    for (i, line) in input::read_lines(reader)?.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let expr = expr::parse(line, precedence).map_err(|err| err.at_line(i + 1))?;
+        expressions.push((i + 1, expr));
+    }
+
+    Ok(expressions)
+}
+
+/// Sum of every expression in the input, evaluated as `--arithmetic` says.
+pub(crate) fn sum(
+    reader: &mut dyn Read,
+    options: &Options,
+    precedence: &Precedence,
+) -> Result<String, InputError> {
+    let expressions = read_input(reader, precedence)?;
+
+    match options.parse_or("arithmetic", Arithmetic::Checked)? {
+        Arithmetic::Checked => {
+            let mut sum: i64 = 0;
+            for (line, e) in expressions.iter() {
+                let value = e
+                    .eval()
+                    .map_err(|err| InputError::parse(*line, err.to_string()))?;
+                sum = sum.checked_add(value).ok_or_else(|| {
+                    InputError::invalid("sum overflows 64 bits, try --arithmetic big")
+                })?;
+            }
+            Ok(sum.to_string())
+        }
+        Arithmetic::Big => {
+            let sum: BigInt = expressions.iter().map(|(_, e)| e.eval_big()).sum();
+            Ok(sum.to_string())
+        }
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        sum(reader, options, &Precedence::EQUAL)
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["arithmetic"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_reader() {
+        let test_input = "1 + 2 * 3 + 4 * 5 + 6
+1 + (2 * 3) + (4 * (5 + 6))
+2 * 3 + (4 * 5)
+5 + (8 * 3 + 9 + 3 * 4 * 3)
+5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))
+((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+        let output = read_input(test_input.as_bytes(), &Precedence::EQUAL).unwrap();
+
+        println!("output = {:?}", output);
+        assert_eq!(output.len(), 6);
+
+        assert_eq!(output[0].1.eval().unwrap(), 71);
+        assert_eq!(output[1].1.eval().unwrap(), 51);
+        assert_eq!(output[2].1.eval().unwrap(), 26);
+        assert_eq!(output[3].1.eval().unwrap(), 437);
+        assert_eq!(output[4].1.eval().unwrap(), 12240);
+        assert_eq!(output[5].1.eval().unwrap(), 13632);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = read_input("1 + 2\n(3 * 4\n".as_bytes(), &Precedence::EQUAL).unwrap_err();
+
+        assert!(matches!(err, InputError::Parse { line: 2, .. }));
+        assert_eq!(
+            err.to_string(),
+            "line 2: column 1: unclosed '('\n    (3 * 4\n    ^"
+        );
+
+        let err = read_input("1 + 2 $ 3".as_bytes(), &Precedence::EQUAL).unwrap_err();
+        assert!(err.to_string().ends_with("\n    1 + 2 $ 3\n          ^"));
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let output = read_input("\n1 + 2\n  \n3 * 4\n\n".as_bytes(), &Precedence::EQUAL).unwrap();
+        assert_eq!(
+            output.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+            vec![2, 4]
+        );
+
+        let err = Solver
+            .solve(&mut "1\n\n9223372036854775807 + 1\n".as_bytes())
+            .unwrap_err();
+        assert!(matches!(err, InputError::Parse { line: 3, .. }));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "3000000000 * 3000000000\n9000000000000000000 + 1\n3000000000 * 3000000000\n";
+        let mut options = Options::new();
+
+        let err = Solver
+            .solve_with(&mut input.as_bytes(), &options)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: sum overflows 64 bits, try --arithmetic big"
+        );
+        let err = Solver
+            .solve_with(&mut "2 * 9223372036854775807".as_bytes(), &options)
+            .unwrap_err();
+        assert!(matches!(err, InputError::Parse { line: 1, .. }));
+        assert_eq!(
+            err.to_string(),
+            "line 1: (2 * 9223372036854775807) overflows 64 bits"
+        );
+
+        options.set("arithmetic", "big");
+        assert_eq!(
+            Solver.solve_with(&mut input.as_bytes(), &options).unwrap(),
+            "27000000000000000001"
+        );
+        assert_eq!(
+            Solver
+                .solve_with(&mut "99999999999999999999 + 1".as_bytes(), &options)
+                .unwrap(),
+            "100000000000000000000"
+        );
+
+        options.set("arithmetic", "wrapping");
+        assert!(Solver.solve_with(&mut input.as_bytes(), &options).is_err());
+    }
+}
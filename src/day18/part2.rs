@@ -0,0 +1,58 @@
+use crate::day18::expr::Precedence;
+use crate::day18::part1::sum;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        sum(reader, options, &Precedence::ADDITION_FIRST)
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["arithmetic"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day18::part1::read_input;
+
+    #[test]
+    fn test_addition_first() {
+        let output = read_input("11 * 22 + 33".as_bytes(), &Precedence::ADDITION_FIRST).unwrap();
+
+        assert_eq!(output.len(), 1);
+        println!("{}", output[0].1);
+        assert_eq!(output[0].1.eval().unwrap(), 11 * (22 + 33));
+    }
+
+    #[test]
+    fn test_input_reader() {
+        let test_input = "1 + 2 * 3 + 4 * 5 + 6
+1 + (2 * 3) + (4 * (5 + 6))
+2 * 3 + (4 * 5)
+5 + (8 * 3 + 9 + 3 * 4 * 3)
+5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))
+((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2";
+        let output = read_input(test_input.as_bytes(), &Precedence::ADDITION_FIRST).unwrap();
+
+        println!("output = {:?}", output);
+        assert_eq!(output.len(), 6);
+
+        assert_eq!(output[0].1.eval().unwrap(), 231);
+        assert_eq!(output[1].1.eval().unwrap(), 51);
+        assert_eq!(output[2].1.eval().unwrap(), 46);
+        assert_eq!(output[3].1.eval().unwrap(), 1445);
+        assert_eq!(output[4].1.eval().unwrap(), 669060);
+        assert_eq!(output[5].1.eval().unwrap(), 23340);
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Rule {
+    Char(String),
+    Concat(Vec<usize>),
+    Union(Vec<Vec<usize>>),
+}
+
+impl Rule {
+    /// The rules of the `i`th alternative, None past the last one. Literals
+    /// have no alternatives.
+    pub fn alternative(&self, i: usize) -> Option<&[usize]> {
+        match (self, i) {
+            (Rule::Concat(a), 0) => Some(a),
+            (Rule::Union(alternatives), i) => alternatives.get(i).map(|a| a.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Every rule this one refers to, in order and with repeats.
+    pub fn references(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..)
+            .map_while(move |i| self.alternative(i))
+            .flat_map(|a| a.iter().copied())
+    }
+}
+
+/// Parses a rule written as `<number>: <rule>`, where the rule is a literal
+/// in double quotes or any number of rule sequences separated by `|`.
+pub fn parse_rule(line: &str) -> Result<(usize, Rule), String> {
+    let (id, body) = line
+        .split_once(':')
+        .ok_or_else(|| String::from("expected \"<number>: <rule>\""))?;
+    let id = id
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid rule number {:?}", id.trim()))?;
+
+    let body = body.trim();
+    if let Some(quoted) = body.strip_prefix('"') {
+        return match quoted.strip_suffix('"') {
+            Some(literal) if !literal.contains('"') => Ok((id, Rule::Char(literal.to_owned()))),
+            _ => Err(format!("malformed literal {}", body)),
+        };
+    }
+
+    let mut alternatives = Vec::new();
+    for alternative in body.split('|') {
+        let sequence = alternative
+            .split_whitespace()
+            .map(|r| {
+                r.parse()
+                    .map_err(|_| format!("invalid rule reference {:?}", r))
+            })
+            .collect::<Result<Vec<usize>, String>>()?;
+        if sequence.is_empty() {
+            return Err(String::from("empty alternative"));
+        }
+        alternatives.push(sequence);
+    }
+
+    if alternatives.len() == 1 {
+        Ok((id, Rule::Concat(alternatives.remove(0))))
+    } else {
+        Ok((id, Rule::Union(alternatives)))
+    }
+}
+
+// a partially matched alternative: `dot` of its rules have matched the
+// message from `origin` on
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Item {
+    rule: usize,
+    alternative: usize,
+    dot: usize,
+    origin: usize,
+}
+
+/// Numbered rules that match messages exactly, however they refer to each
+/// other, using an Earley parser. A reference to a rule that does not exist
+/// matches nothing.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Grammar {
+    rules: HashMap<usize, Rule>,
+}
+
+impl From<HashMap<usize, Rule>> for Grammar {
+    fn from(rules: HashMap<usize, Rule>) -> Self {
+        Grammar { rules }
+    }
+}
+
+impl Grammar {
+    pub fn new() -> Self {
+        Grammar::default()
+    }
+
+    /// Adds or replaces a rule, returning the one it replaced.
+    pub fn insert(&mut self, id: usize, rule: Rule) -> Option<Rule> {
+        self.rules.insert(id, rule)
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Rule> {
+        self.rules.get(&id)
+    }
+
+    /// Checks that `start` and every rule it leads to are defined, and that
+    /// every rule can be reached from `start`.
+    pub fn check(&self, start: usize) -> Result<(), String> {
+        if !self.rules.contains_key(&start) {
+            return Err(format!("rule {} is not defined", start));
+        }
+
+        let mut reachable = HashSet::new();
+        reachable.insert(start);
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            for reference in self.rules[&id].references() {
+                if !self.rules.contains_key(&reference) {
+                    return Err(format!(
+                        "rule {} refers to undefined rule {}",
+                        id, reference
+                    ));
+                }
+                if reachable.insert(reference) {
+                    stack.push(reference);
+                }
+            }
+        }
+
+        let mut unreachable: Vec<usize> = self
+            .rules
+            .keys()
+            .copied()
+            .filter(|id| !reachable.contains(id))
+            .collect();
+        unreachable.sort_unstable();
+        let unreachable: Vec<String> = unreachable.iter().map(|id| id.to_string()).collect();
+        match unreachable.len() {
+            0 => Ok(()),
+            1 => Err(format!(
+                "rule {} is not reachable from rule {}",
+                unreachable[0], start
+            )),
+            _ => Err(format!(
+                "rules {} are not reachable from rule {}",
+                unreachable.join(", "),
+                start
+            )),
+        }
+    }
+
+    fn symbols(&self, item: &Item) -> &[usize] {
+        self.rules[&item.rule]
+            .alternative(item.alternative)
+            .unwrap()
+    }
+
+    /// Whether rule `id` matches all of `message`.
+    pub fn matches(&self, id: usize, message: &str) -> bool {
+        let alternatives = |rule: usize| {
+            let rule = self.rules.get(&rule);
+            (0..).map_while(move |i| rule.and_then(|rule| rule.alternative(i)))
+        };
+
+        if let Some(Rule::Char(s)) = self.rules.get(&id) {
+            return message == s;
+        }
+
+        // one set of items per byte offset of the message
+        let len = message.len();
+        let mut sets: Vec<Vec<Item>> = vec![Vec::new(); len + 1];
+        let mut seen: Vec<HashSet<Item>> = vec![HashSet::new(); len + 1];
+        let mut add = |sets: &mut Vec<Vec<Item>>, position: usize, item: Item| {
+            if seen[position].insert(item) {
+                sets[position].push(item);
+            }
+        };
+
+        for (alternative, _) in alternatives(id).enumerate() {
+            let item = Item {
+                rule: id,
+                alternative,
+                dot: 0,
+                origin: 0,
+            };
+            add(&mut sets, 0, item);
+        }
+
+        for position in 0..=len {
+            // rules that matched the empty string here, so that items
+            // predicting them later can still move past them
+            let mut empty: HashSet<usize> = HashSet::new();
+
+            let mut next = 0;
+            while next < sets[position].len() {
+                let item = sets[position][next];
+                next += 1;
+
+                let advanced = Item {
+                    dot: item.dot + 1,
+                    ..item
+                };
+                match self.symbols(&item).get(item.dot) {
+                    // complete: move on every item that was waiting for it
+                    None => {
+                        if item.origin == position {
+                            empty.insert(item.rule);
+                        }
+                        let mut waiting = 0;
+                        while waiting < sets[item.origin].len() {
+                            let parent = sets[item.origin][waiting];
+                            waiting += 1;
+                            if self.symbols(&parent).get(parent.dot) == Some(&item.rule) {
+                                let parent = Item {
+                                    dot: parent.dot + 1,
+                                    ..parent
+                                };
+                                add(&mut sets, position, parent);
+                            }
+                        }
+                    }
+                    // scan
+                    Some(rule) => match self.rules.get(rule) {
+                        Some(Rule::Char(s)) => {
+                            if message[position..].starts_with(s.as_str()) {
+                                add(&mut sets, position + s.len(), advanced);
+                            }
+                        }
+                        // predict
+                        _ => {
+                            for (alternative, _) in alternatives(*rule).enumerate() {
+                                let prediction = Item {
+                                    rule: *rule,
+                                    alternative,
+                                    dot: 0,
+                                    origin: position,
+                                };
+                                add(&mut sets, position, prediction);
+                            }
+                            if empty.contains(rule) {
+                                add(&mut sets, position, advanced);
+                            }
+                        }
+                    },
+                }
+            }
+        }
+
+        sets[len]
+            .iter()
+            .any(|item| item.rule == id && item.origin == 0 && item.dot == self.symbols(item).len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grammar(rules: &[(usize, Rule)]) -> Grammar {
+        let mut grammar = Grammar::new();
+        for (id, rule) in rules {
+            grammar.insert(*id, rule.clone());
+        }
+        grammar
+    }
+
+    #[test]
+    fn test_literals_and_sequences() {
+        let grammar = grammar(&[
+            (0, Rule::Concat(vec![1, 2])),
+            (1, Rule::Char(String::from("a"))),
+            (2, Rule::Union(vec![vec![1, 3], vec![3, 1]])),
+            (3, Rule::Char(String::from("bc"))),
+            (4, Rule::Concat(vec![9])),
+        ]);
+
+        assert!(grammar.matches(0, "aabc"));
+        assert!(grammar.matches(0, "abca"));
+        assert!(!grammar.matches(0, "abc"));
+        assert!(!grammar.matches(0, "aabca"));
+        assert!(!grammar.matches(0, ""));
+        assert!(grammar.matches(3, "bc"));
+        assert!(!grammar.matches(4, "a"));
+        assert!(!grammar.matches(5, ""));
+    }
+
+    #[test]
+    fn test_recursion() {
+        // 0: 1 0 2 | 1 2, a^n b^n which no regular expression matches
+        let grammar = grammar(&[
+            (0, Rule::Union(vec![vec![1, 0, 2], vec![1, 2]])),
+            (1, Rule::Char(String::from("a"))),
+            (2, Rule::Char(String::from("b"))),
+            // left recursion, one or more a
+            (3, Rule::Union(vec![vec![3, 1], vec![1]])),
+        ]);
+
+        for n in 1..30 {
+            let balanced = "a".repeat(n) + &"b".repeat(n);
+            assert!(grammar.matches(0, &balanced));
+            assert!(!grammar.matches(0, &(balanced.clone() + "b")));
+            assert!(!grammar.matches(0, &("a".to_owned() + &balanced)));
+            assert!(grammar.matches(3, &"a".repeat(n)));
+        }
+        assert!(!grammar.matches(0, "abab"));
+        assert!(!grammar.matches(3, ""));
+    }
+
+    #[test]
+    fn test_empty_rules() {
+        // 1 is empty or "a", so 0 is up to three a, b and up to one more a
+        let grammar = grammar(&[
+            (0, Rule::Concat(vec![1, 2, 1])),
+            (1, Rule::Union(vec![vec![], vec![3]])),
+            (2, Rule::Concat(vec![1, 1, 4])),
+            (3, Rule::Char(String::from("a"))),
+            (4, Rule::Char(String::from("b"))),
+            (5, Rule::Union(vec![vec![5, 1], vec![6]])),
+            (6, Rule::Char(String::new())),
+        ]);
+
+        for message in ["b", "ab", "ba", "aaab", "aaaba"] {
+            assert!(grammar.matches(0, message), "{}", message);
+        }
+        for message in ["", "aaaab", "bb", "abaa"] {
+            assert!(!grammar.matches(0, message), "{}", message);
+        }
+        assert!(grammar.matches(5, ""));
+        assert!(grammar.matches(5, "aaa"));
+        assert!(!grammar.matches(5, "ab"));
+    }
+
+    #[test]
+    fn test_parse_rule() {
+        assert_eq!(
+            parse_rule("4: \"a\""),
+            Ok((4, Rule::Char(String::from("a"))))
+        );
+        assert_eq!(parse_rule("0: 4 1 5"), Ok((0, Rule::Concat(vec![4, 1, 5]))));
+        assert_eq!(
+            parse_rule("12:1 2|3 | 4 5 6"),
+            Ok((12, Rule::Union(vec![vec![1, 2], vec![3], vec![4, 5, 6]])))
+        );
+
+        assert_eq!(
+            parse_rule("4 \"a\"").unwrap_err(),
+            "expected \"<number>: <rule>\""
+        );
+        assert_eq!(parse_rule("x: 1").unwrap_err(), "invalid rule number \"x\"");
+        assert_eq!(
+            parse_rule("1: 2 b").unwrap_err(),
+            "invalid rule reference \"b\""
+        );
+        assert_eq!(parse_rule("1: 2 | | 3").unwrap_err(), "empty alternative");
+        assert_eq!(parse_rule("1:").unwrap_err(), "empty alternative");
+        assert_eq!(parse_rule("1: \"a").unwrap_err(), "malformed literal \"a");
+        assert_eq!(
+            parse_rule("1: \"a\" \"b\"").unwrap_err(),
+            "malformed literal \"a\" \"b\""
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut grammar = grammar(&[
+            (0, Rule::Union(vec![vec![1], vec![1, 0]])),
+            (1, Rule::Char(String::from("a"))),
+        ]);
+        assert_eq!(grammar.check(0), Ok(()));
+        assert_eq!(grammar.check(2), Err(String::from("rule 2 is not defined")));
+        assert_eq!(
+            grammar.check(1),
+            Err(String::from("rule 0 is not reachable from rule 1"))
+        );
+
+        grammar.insert(12, Rule::Concat(vec![1]));
+        grammar.insert(3, Rule::Concat(vec![12]));
+        assert_eq!(
+            grammar.check(0),
+            Err(String::from("rules 3, 12 are not reachable from rule 0"))
+        );
+
+        grammar.insert(1, Rule::Concat(vec![3, 7]));
+        assert_eq!(
+            grammar.check(0),
+            Err(String::from("rule 1 refers to undefined rule 7"))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::day19::grammar;
+use crate::day19::grammar::Grammar;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::io::Read;
+
+/// Reads the rules and the messages after them. The rules are only parsed
+/// here, see `Grammar::check`.
+pub(crate) fn read_input(reader: impl Read) -> Result<(Grammar, Vec<String>), InputError> {
+    let lines = input::read_lines(reader)?;
+    let mut lines = lines.into_iter().enumerate();
+
+    let mut grammar = Grammar::new();
+    let mut defined = HashSet::new();
+    for (i, line) in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+        let (id, rule) = grammar::parse_rule(&line).map_err(|err| InputError::parse(i + 1, err))?;
+        if !defined.insert(id) {
+            return Err(InputError::parse(
+                i + 1,
+                format!("rule {} is defined twice", id),
+            ));
+        }
+        grammar.insert(id, rule);
+    }
+
+    let messages = lines
+        .map(|(_, line)| line)
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+
+    Ok((grammar, messages))
+}
+
+/// Number of messages matching rule 0 once `rules` and then every
+/// `--rule` setting have replaced the rules they define.
+pub(crate) fn count_matches(
+    reader: &mut dyn Read,
+    options: &Options,
+    rules: &[&str],
+) -> Result<String, InputError> {
+    let (mut grammar, messages) = read_input(reader)?;
+
+    for &rule in rules {
+        let (id, rule) = grammar::parse_rule(rule).map_err(InputError::invalid)?;
+        grammar.insert(id, rule);
+    }
+    for rule in options.get_all("rule") {
+        let (id, rule) = grammar::parse_rule(rule)
+            .map_err(|err| InputError::invalid(format!("--rule {:?}: {}", rule, err)))?;
+        grammar.insert(id, rule);
+    }
+    grammar.check(0).map_err(InputError::invalid)?;
+
+    let matching = messages.iter().filter(|m| grammar.matches(0, m)).count();
+    Ok(matching.to_string())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        count_matches(reader, options, &[])
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["rule"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_reader() {
+        let test_input = r#"0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"#;
+        let output = read_input(test_input.as_bytes()).unwrap();
+
+        let matching = output.1.iter().filter(|m| output.0.matches(0, m)).count();
+        assert_eq!(matching, 2);
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let error_line = |input: &str| match read_input(input.as_bytes()) {
+            Err(InputError::Parse { line, .. }) => line,
+            other => panic!("unexpected {:?}", other),
+        };
+
+        assert_eq!(error_line("0: 1\n1 \"a\"\n\na"), 2);
+        assert_eq!(error_line("0: 1 | 2 |\n1: \"a\"\n2: \"b\"\n\na"), 1);
+        assert_eq!(error_line("0: 1\n1: \"a\"\n1: \"b\"\n\na"), 3);
+
+        let solve = |input: &str| {
+            Solver
+                .solve(&mut input.as_bytes())
+                .map_err(|err| err.to_string())
+        };
+        assert_eq!(
+            solve("0: 1 2\n1: \"a\"\n\na"),
+            Err(String::from(
+                "invalid input: rule 0 refers to undefined rule 2"
+            ))
+        );
+        assert_eq!(
+            solve("0: 1\n1: \"a\"\n2: \"b\"\n\na"),
+            Err(String::from(
+                "invalid input: rule 2 is not reachable from rule 0"
+            ))
+        );
+        assert_eq!(
+            solve("0: 1 | 2 | 1 2\n1: \"a\"\n2: \"b\"\n\na\nb\nab\nba"),
+            Ok(String::from("3"))
+        );
+    }
+
+    #[test]
+    fn test_rule_option() {
+        let test_input = "0: 1 2\n1: \"a\"\n2: \"b\"\n\nab\naab\naaab\nb";
+        let mut options = Options::new();
+
+        assert_eq!(
+            Solver
+                .solve_with(&mut test_input.as_bytes(), &options)
+                .unwrap(),
+            "1"
+        );
+
+        options.set("rule", "1: 3 | 3 1");
+        options.set("rule", "3: \"a\"");
+        assert_eq!(
+            Solver
+                .solve_with(&mut test_input.as_bytes(), &options)
+                .unwrap(),
+            "3"
+        );
+
+        options.set("rule", "1 = 3");
+        let err = Solver
+            .solve_with(&mut test_input.as_bytes(), &options)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: --rule \"1 = 3\": expected \"<number>: <rule>\""
+        );
+    }
+}
@@ -0,0 +1,411 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use regex::Regex;
+use std::io::Read;
+
+pub(crate) fn read_input(reader: impl Read) -> Result<Vec<Tile>, InputError> {
+    let mut tiles = Vec::new();
+
+    let id_regex = Regex::new(r"^Tile (\d+):$").unwrap();
+
+    for group in input::read_groups(reader)? {
+        let mut current_tile = Tile::new();
+
+        // groups are never empty
+        let (header, rows) = group.split_first().unwrap();
+        let captures = id_regex.captures(header).ok_or_else(|| {
+            InputError::invalid(format!("expected a tile header, found {:?}", header))
+        })?;
+        current_tile.id = captures[1]
+            .parse()
+            .map_err(|err| InputError::invalid(format!("invalid tile id {:?}: {}", header, err)))?;
+
+        for x in rows {
+            if x.chars().count() != TILE_SIZE {
+                return Err(InputError::invalid(format!(
+                    "tile {} has a row of {} pixels, expected {}",
+                    current_tile.id,
+                    x.chars().count(),
+                    TILE_SIZE
+                )));
+            }
+            for c in x.chars() {
+                current_tile.pixels.push(c);
+            }
+        }
+
+        if rows.len() != TILE_SIZE {
+            return Err(InputError::invalid(format!(
+                "tile {} has {} rows, expected {}",
+                current_tile.id,
+                rows.len(),
+                TILE_SIZE
+            )));
+        }
+
+        current_tile.calculcate_edges();
+        tiles.push(current_tile);
+    }
+
+    Ok(tiles)
+}
+
+pub(crate) const TILE_SIZE: usize = 10;
+
+/// Rotates a square, row-major grid of pixels 90 degrees clockwise.
+pub(crate) fn rotate_pixels(pixels: &[char], size: usize) -> Vec<char> {
+    let mut rotated = Vec::with_capacity(pixels.len());
+    for y in 0..size {
+        for x in 0..size {
+            rotated.push(pixels[(size - 1 - x) * size + y]);
+        }
+    }
+    rotated
+}
+
+/// Mirrors a square, row-major grid of pixels left to right.
+pub(crate) fn flip_pixels(pixels: &[char], size: usize) -> Vec<char> {
+    let mut flipped = Vec::with_capacity(pixels.len());
+    for y in 0..size {
+        for x in 0..size {
+            flipped.push(pixels[y * size + size - 1 - x]);
+        }
+    }
+    flipped
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Tile {
+    pub(crate) id: i64,
+    pub(crate) pixels: Vec<char>,
+    // top, right, bottom and left edge, read left to right and top to bottom
+    pub(crate) edges: Vec<String>,
+}
+
+impl Tile {
+    fn new() -> Tile {
+        Tile {
+            id: 0,
+            pixels: Vec::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    fn calculcate_edges(&mut self) {
+        self.edges.clear();
+
+        let mut top_edge = String::with_capacity(TILE_SIZE);
+        for x in 0..TILE_SIZE {
+            top_edge.push(self.pixels[x]);
+        }
+        self.edges.push(top_edge);
+
+        let mut right_edge = String::with_capacity(TILE_SIZE);
+        for y in 0..TILE_SIZE {
+            right_edge.push(self.pixels[TILE_SIZE - 1 + y * TILE_SIZE]);
+        }
+        self.edges.push(right_edge);
+
+        let mut bottom_edge = String::with_capacity(TILE_SIZE);
+        for x in 0..TILE_SIZE {
+            bottom_edge.push(self.pixels[TILE_SIZE * (TILE_SIZE - 1) + x]);
+        }
+        self.edges.push(bottom_edge);
+
+        let mut left_edge = String::with_capacity(TILE_SIZE);
+        for y in 0..TILE_SIZE {
+            left_edge.push(self.pixels[TILE_SIZE * y]);
+        }
+        self.edges.push(left_edge);
+    }
+
+    pub(crate) fn find_potential_neighbours(&self, tiles: &[Tile]) -> Vec<i64> {
+        let mut potential_neighbours = Vec::new();
+
+        for t in tiles.iter() {
+            if self.id == t.id {
+                continue;
+            }
+
+            for e in &self.edges {
+                let reverse_e: String = e.chars().rev().collect();
+
+                if t.edges.contains(e) || t.edges.contains(&reverse_e) {
+                    potential_neighbours.push(t.id);
+                }
+            }
+        }
+
+        potential_neighbours
+    }
+
+    pub(crate) fn is_corner_tile(&self, tiles: &[Tile]) -> bool {
+        self.find_potential_neighbours(tiles).len() == 2
+    }
+
+    pub(crate) fn rotate(&mut self) {
+        self.pixels = rotate_pixels(&self.pixels, TILE_SIZE);
+        self.calculcate_edges();
+    }
+
+    pub(crate) fn flip(&mut self) {
+        self.pixels = flip_pixels(&self.pixels, TILE_SIZE);
+        self.calculcate_edges();
+    }
+
+    /// All eight rotations and reflections of the tile.
+    pub(crate) fn orientations(&self) -> Vec<Tile> {
+        let mut orientations = Vec::with_capacity(8);
+
+        let mut tile = self.clone();
+        for _ in 0..2 {
+            for _ in 0..4 {
+                orientations.push(tile.clone());
+                tile.rotate();
+            }
+            tile.flip();
+        }
+
+        orientations
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let tiles = read_input(reader)?;
+
+        let corner_tiles: Vec<&Tile> = tiles.iter().filter(|t| t.is_corner_tile(&tiles)).collect();
+
+        let answer: i64 = corner_tiles.iter().map(|t| t.id).product();
+
+        Ok(answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_input() {
+        let test_input = get_test_input().as_bytes();
+
+        let tiles = read_input(test_input).unwrap();
+
+        assert_eq!(tiles.len(), 9);
+        assert_eq!(tiles[0].id, 2311);
+        assert_eq!(tiles[0].edges[0], "..##.#..#.");
+        assert_eq!(tiles[0].edges[1], "...#.##..#");
+        assert_eq!(tiles[0].edges[2], "..###..###");
+        assert_eq!(tiles[0].edges[3], ".#####..#.");
+    }
+
+    #[test]
+    fn test_tile_size() {
+        let err = read_input("Tile 1:\n##########\n#.#.#\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, InputError::Invalid(_)));
+
+        let err = read_input("Tile 2:\n##########\n..........\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: tile 2 has 2 rows, expected 10"
+        );
+    }
+
+    #[test]
+    fn test_tile_header() {
+        let tile = format!("{}\n", ["##########"; 10].join("\n"));
+
+        let err = read_input(tile.as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: expected a tile header, found \"##########\""
+        );
+
+        let input = format!("Tile 99999999999999999999:\n{}", tile);
+        let err = read_input(input.as_bytes()).unwrap_err();
+        assert!(matches!(err, InputError::Invalid(_)));
+
+        let input = format!("Tile 3:\n{}\nTile 4:\n{}", tile, tile);
+        let tiles = read_input(input.as_bytes()).unwrap();
+        assert_eq!(tiles[1].id, 4);
+    }
+
+    #[test]
+    fn test_find_corner_tiles() {
+        let test_input = get_test_input().as_bytes();
+
+        let tiles = read_input(test_input).unwrap();
+
+        let corner_tiles: Vec<&Tile> = tiles.iter().filter(|t| t.is_corner_tile(&tiles)).collect();
+
+        assert_eq!(corner_tiles.len(), 4);
+
+        let answer: i64 = corner_tiles.iter().map(|t| t.id).product();
+
+        assert_eq!(answer, 20899048083289);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let test_input = get_test_input().as_bytes();
+
+        let mut tile = read_input(test_input).unwrap().remove(0);
+        let original = tile.clone();
+
+        tile.rotate();
+
+        let left: String = original.edges[3].chars().rev().collect();
+        assert_eq!(tile.edges[0], left);
+        assert_eq!(tile.edges[1], original.edges[0]);
+        assert_eq!(tile.pixels[TILE_SIZE - 1], original.pixels[0]);
+
+        for _ in 0..3 {
+            tile.rotate();
+        }
+        assert_eq!(tile.pixels, original.pixels);
+    }
+
+    #[test]
+    fn test_flip() {
+        let test_input = get_test_input().as_bytes();
+
+        let mut tile = read_input(test_input).unwrap().remove(0);
+        let original = tile.clone();
+
+        tile.flip();
+
+        let top: String = original.edges[0].chars().rev().collect();
+        assert_eq!(tile.edges[0], top);
+        assert_eq!(tile.edges[1], original.edges[3]);
+        assert_eq!(tile.edges[3], original.edges[1]);
+
+        tile.flip();
+        assert_eq!(tile.pixels, original.pixels);
+    }
+
+    #[test]
+    fn test_orientations() {
+        let test_input = get_test_input().as_bytes();
+
+        let tile = read_input(test_input).unwrap().remove(0);
+
+        let mut seen: Vec<Vec<char>> = tile.orientations().into_iter().map(|t| t.pixels).collect();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), 8);
+    }
+
+    fn get_test_input() -> &'static str {
+        "Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###..."
+    }
+}
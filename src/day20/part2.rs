@@ -0,0 +1,288 @@
+use crate::day20::part1::flip_pixels;
+use crate::day20::part1::read_input;
+use crate::day20::part1::rotate_pixels;
+use crate::day20::part1::Tile;
+use crate::day20::part1::TILE_SIZE;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::io::Read;
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+#[derive(Debug, PartialEq, Clone)]
+struct Image {
+    size: usize,
+    pixels: Vec<char>,
+}
+
+impl Image {
+    fn rotate(&mut self) {
+        self.pixels = rotate_pixels(&self.pixels, self.size);
+    }
+
+    fn flip(&mut self) {
+        self.pixels = flip_pixels(&self.pixels, self.size);
+    }
+
+    fn get(&self, x: usize, y: usize) -> char {
+        self.pixels[y * self.size + x]
+    }
+
+    // positions of every sea monster in the current orientation
+    fn find_monsters(&self) -> Vec<(usize, usize)> {
+        let pattern = monster_offsets();
+        let width = SEA_MONSTER[0].len();
+        let height = SEA_MONSTER.len();
+
+        let mut monsters = Vec::new();
+        if self.size < width || self.size < height {
+            return monsters;
+        }
+
+        for y in 0..=self.size - height {
+            for x in 0..=self.size - width {
+                if pattern
+                    .iter()
+                    .all(|&(dx, dy)| self.get(x + dx, y + dy) == '#')
+                {
+                    monsters.push((x, y));
+                }
+            }
+        }
+        monsters
+    }
+
+    // number of '#' pixels that are not part of any sea monster, or None if
+    // there are no monsters in the current orientation
+    fn roughness(&self) -> Option<usize> {
+        let monsters = self.find_monsters();
+        if monsters.is_empty() {
+            return None;
+        }
+
+        let mut monster_pixels = HashSet::new();
+        for (x, y) in monsters {
+            for (dx, dy) in monster_offsets() {
+                monster_pixels.insert((x + dx, y + dy));
+            }
+        }
+
+        let total = self.pixels.iter().filter(|&&c| c == '#').count();
+        Some(total - monster_pixels.len())
+    }
+}
+
+fn monster_offsets() -> Vec<(usize, usize)> {
+    let mut offsets = Vec::new();
+    for (dy, line) in SEA_MONSTER.iter().enumerate() {
+        for (dx, c) in line.chars().enumerate() {
+            if c == '#' {
+                offsets.push((dx, dy));
+            }
+        }
+    }
+    offsets
+}
+
+fn count_matching_edges(edge: &str, tile_id: i64, tiles: &[Tile]) -> usize {
+    let reverse_edge: String = edge.chars().rev().collect();
+
+    tiles
+        .iter()
+        .filter(|t| t.id != tile_id)
+        .filter(|t| {
+            t.edges
+                .iter()
+                .any(|e| e.as_str() == edge || *e == reverse_edge)
+        })
+        .count()
+}
+
+// finds an unused tile with an orientation that fits the given constraints
+fn find_fitting_tile(
+    tiles: &[Tile],
+    used: &HashSet<i64>,
+    left: Option<&str>,
+    top: Option<&str>,
+) -> Option<Tile> {
+    tiles
+        .iter()
+        .filter(|t| !used.contains(&t.id))
+        .flat_map(|t| t.orientations())
+        .find(|t| left.is_none_or(|e| t.edges[3] == e) && top.is_none_or(|e| t.edges[0] == e))
+}
+
+/// Places every tile into a square grid so that all touching edges match.
+fn assemble(tiles: &[Tile]) -> Result<Vec<Vec<Tile>>, InputError> {
+    let side = (tiles.len() as f64).sqrt() as usize;
+    if side * side != tiles.len() || side == 0 {
+        return Err(InputError::invalid(format!(
+            "{} tiles do not form a square",
+            tiles.len()
+        )));
+    }
+
+    // orient a corner tile so that its unmatched edges face up and left
+    let corner = tiles
+        .iter()
+        .find(|t| t.is_corner_tile(tiles))
+        .ok_or_else(|| InputError::invalid("no corner tile found"))?;
+    let top_left = corner
+        .orientations()
+        .into_iter()
+        .find(|t| {
+            count_matching_edges(&t.edges[0], t.id, tiles) == 0
+                && count_matching_edges(&t.edges[3], t.id, tiles) == 0
+        })
+        .ok_or_else(|| InputError::invalid("corner tile cannot be oriented"))?;
+
+    let mut used = HashSet::new();
+    used.insert(top_left.id);
+
+    let mut grid: Vec<Vec<Tile>> = vec![vec![top_left]];
+    for y in 0..side {
+        if y > 0 {
+            grid.push(Vec::with_capacity(side));
+        }
+        for x in 0..side {
+            if x == 0 && y == 0 {
+                continue;
+            }
+
+            let left = if x > 0 {
+                Some(grid[y][x - 1].edges[1].as_str())
+            } else {
+                None
+            };
+            let top = if y > 0 {
+                Some(grid[y - 1][x].edges[2].as_str())
+            } else {
+                None
+            };
+
+            let tile = find_fitting_tile(tiles, &used, left, top).ok_or_else(|| {
+                InputError::invalid(format!("no tile fits at column {} row {}", x, y))
+            })?;
+
+            used.insert(tile.id);
+            grid[y].push(tile);
+        }
+    }
+
+    Ok(grid)
+}
+
+/// Joins the placed tiles into one image with the tile borders removed.
+fn strip_borders(grid: &[Vec<Tile>]) -> Image {
+    let inner = TILE_SIZE - 2;
+    let size = grid.len() * inner;
+
+    let mut pixels = Vec::with_capacity(size * size);
+    for row in grid {
+        for y in 1..TILE_SIZE - 1 {
+            for tile in row {
+                pixels.extend_from_slice(&tile.pixels[y * TILE_SIZE + 1..(y + 1) * TILE_SIZE - 1]);
+            }
+        }
+    }
+
+    Image { size, pixels }
+}
+
+fn water_roughness(tiles: &[Tile]) -> Result<usize, InputError> {
+    let mut image = strip_borders(&assemble(tiles)?);
+
+    for _ in 0..2 {
+        for _ in 0..4 {
+            if let Some(roughness) = image.roughness() {
+                return Ok(roughness);
+            }
+            image.rotate();
+        }
+        image.flip();
+    }
+
+    Err(InputError::invalid("no sea monsters found"))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let tiles = read_input(reader)?;
+
+        Ok(water_roughness(&tiles)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assemble() {
+        let tiles = read_input(get_test_input().as_bytes()).unwrap();
+
+        let grid = assemble(&tiles).unwrap();
+
+        assert_eq!(grid.len(), 3);
+        let mut corners = vec![grid[0][0].id, grid[0][2].id, grid[2][0].id, grid[2][2].id];
+        corners.sort_unstable();
+        assert_eq!(corners, vec![1171, 1951, 2971, 3079]);
+
+        for y in 0..3 {
+            for x in 0..3 {
+                if x > 0 {
+                    assert_eq!(grid[y][x - 1].edges[1], grid[y][x].edges[3]);
+                }
+                if y > 0 {
+                    assert_eq!(grid[y - 1][x].edges[2], grid[y][x].edges[0]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strip_borders() {
+        let tiles = read_input(get_test_input().as_bytes()).unwrap();
+
+        let image = strip_borders(&assemble(&tiles).unwrap());
+
+        assert_eq!(image.size, 24);
+        assert_eq!(image.pixels.len(), 24 * 24);
+    }
+
+    #[test]
+    fn test_find_monsters() {
+        let tiles = read_input(get_test_input().as_bytes()).unwrap();
+
+        let mut image = strip_borders(&assemble(&tiles).unwrap());
+
+        let mut found = Vec::new();
+        for _ in 0..2 {
+            for _ in 0..4 {
+                found.push(image.find_monsters().len());
+                image.rotate();
+            }
+            image.flip();
+        }
+        found.sort_unstable();
+        assert_eq!(found, vec![0, 0, 0, 0, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn test_water_roughness() {
+        let tiles = read_input(get_test_input().as_bytes()).unwrap();
+
+        assert_eq!(water_roughness(&tiles).unwrap(), 273);
+    }
+
+    fn get_test_input() -> &'static str {
+        include_str!("sample.txt")
+    }
+}
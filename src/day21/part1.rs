@@ -0,0 +1,219 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io::Read;
+
+type Food = (Vec<String>, Vec<String>);
+
+fn parse_allergens(rule: &str) -> Option<Food> {
+    let rule_re = Regex::new(r"^(.*) \(contains (.*)\)$").unwrap();
+    if let Some(rule_capt) = rule_re.captures(rule) {
+        if rule_capt.len() != 3 {
+            return None;
+        };
+
+        let ingredients_capt = rule_capt.get(1).map_or("", |m| m.as_str());
+        let allergens_capt = rule_capt.get(2).map_or("", |m| m.as_str());
+
+        let ingredients = ingredients_capt.split(' ').map(|i| i.to_owned()).collect();
+        let allergens = allergens_capt
+            .split(',')
+            .map(|i| i.trim().to_owned())
+            .collect();
+
+        return Some((ingredients, allergens));
+    }
+    None
+}
+
+pub(crate) fn read_input(reader: impl Read) -> Result<Vec<Food>, InputError> {
+    let mut result: Vec<Food> = Vec::new();
+
+    for (i, x) in input::read_lines(reader)?.iter().enumerate() {
+        if !x.is_empty() {
+            let (ingredients, allergens) = parse_allergens(x)
+                .ok_or_else(|| InputError::parse(i + 1, format!("invalid food {:?}", x)))?;
+            result.push((ingredients, allergens));
+        }
+    }
+
+    Ok(result)
+}
+
+pub(crate) fn map_candidates(foods: Vec<Food>) -> HashMap<String, Vec<Vec<String>>> {
+    let mut candidates: HashMap<String, Vec<Vec<String>>> = HashMap::new();
+
+    for food in foods {
+        for allergen in food.1 {
+            candidates.entry(allergen).or_default().push(food.0.clone());
+        }
+    }
+
+    candidates
+}
+
+pub(crate) fn find_allergens(
+    candidates: &HashMap<String, Vec<Vec<String>>>,
+) -> Result<HashMap<String, String>, InputError> {
+    let mut allergens: HashMap<String, String> = HashMap::new();
+
+    let mut names: Vec<&String> = candidates.keys().collect();
+    names.sort_unstable();
+
+    while allergens.len() < candidates.len() {
+        let mut unresolved: Vec<String> = Vec::new();
+        let mut found = false;
+
+        for &a in names.iter() {
+            if allergens.values().any(|v| v == a) {
+                continue;
+            }
+
+            let i = &candidates[a];
+            let mut temp_set: HashSet<&String> = i.first().unwrap().iter().collect();
+
+            i.iter().skip(1).for_each(|i| {
+                let other_set: HashSet<&String> = i.iter().collect();
+                temp_set = temp_set.intersection(&other_set).copied().collect();
+            });
+
+            // and remove ingredients that we've always found
+            for found_ingredient in allergens.keys() {
+                temp_set.remove(found_ingredient);
+            }
+
+            match temp_set.len() {
+                0 => {
+                    return Err(InputError::invalid(format!(
+                        "no ingredient can contain {}",
+                        a
+                    )))
+                }
+                // only one ingredient can be allegren
+                1 => {
+                    let ingredient = temp_set.into_iter().next().unwrap();
+                    allergens.insert(ingredient.to_owned(), a.to_owned());
+                    found = true;
+                }
+                _ => {
+                    let mut remaining: Vec<&str> = temp_set.iter().map(|s| s.as_str()).collect();
+                    remaining.sort_unstable();
+                    unresolved.push(format!("{} ({})", a, remaining.join(", ")));
+                }
+            }
+        }
+
+        if !found {
+            return Err(InputError::invalid(format!(
+                "ambiguous allergens: {}",
+                unresolved.join(", ")
+            )));
+        }
+    }
+
+    Ok(allergens)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let foods = read_input(reader)?;
+
+        let candidates = map_candidates(foods.clone());
+
+        let allergens = find_allergens(&candidates)?;
+
+        let answer = foods.iter().fold(0, |a, f| {
+            a + f.0.iter().filter(|&i| !allergens.contains_key(i)).count()
+        });
+        Ok(answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_reader() {
+        let foods = read_input(get_test_input().as_bytes()).unwrap();
+
+        let candidates = map_candidates(foods);
+
+        assert_eq!(candidates.len(), 3);
+        assert_eq!(
+            candidates["fish"],
+            vec![
+                vec!["mxmxvkd", "kfcds", "sqjhc", "nhms"],
+                vec!["sqjhc", "mxmxvkd", "sbzzf"]
+            ]
+        );
+        assert_eq!(candidates["soy"], vec![vec!["sqjhc", "fvjkl"]]);
+        assert_eq!(
+            candidates["dairy"],
+            vec![
+                vec!["mxmxvkd", "kfcds", "sqjhc", "nhms"],
+                vec!["trh", "fvjkl", "sbzzf", "mxmxvkd"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_allergens() {
+        let foods = read_input(get_test_input().as_bytes()).unwrap();
+
+        let candidates = map_candidates(foods.clone());
+
+        let allergens = find_allergens(&candidates).unwrap();
+
+        assert_eq!(allergens["mxmxvkd"], "dairy");
+        assert_eq!(allergens["sqjhc"], "fish");
+        assert_eq!(allergens["fvjkl"], "soy");
+
+        let answer = foods.iter().fold(0, |a, f| {
+            a + f.0.iter().filter(|&i| !allergens.contains_key(i)).count()
+        });
+        assert_eq!(answer, 5);
+    }
+
+    #[test]
+    fn test_find_allergens_ambiguous() {
+        let foods = read_input("a b (contains dairy)\na b (contains fish)".as_bytes()).unwrap();
+
+        let candidates = map_candidates(foods);
+
+        match find_allergens(&candidates) {
+            Err(InputError::Invalid(message)) => {
+                assert_eq!(message, "ambiguous allergens: dairy (a, b), fish (a, b)")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_find_allergens_unsatisfiable() {
+        let foods = read_input("a (contains dairy)\nb (contains dairy)".as_bytes()).unwrap();
+
+        let candidates = map_candidates(foods);
+
+        match find_allergens(&candidates) {
+            Err(InputError::Invalid(message)) => {
+                assert_eq!(message, "no ingredient can contain dairy")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    fn get_test_input() -> String {
+        String::from(
+            "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)",
+        )
+    }
+}
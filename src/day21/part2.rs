@@ -0,0 +1,62 @@
+use crate::day21::part1::find_allergens;
+use crate::day21::part1::map_candidates;
+use crate::day21::part1::read_input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::io::Read;
+
+// dangerous ingredients joined by commas, sorted alphabetically by their allergen
+fn canonical_dangerous_list(allergens: &HashMap<String, String>) -> String {
+    let mut dangerous: Vec<(&String, &String)> = allergens.iter().collect();
+    dangerous.sort_unstable_by_key(|&(_, v)| v);
+
+    let sorted: Vec<&str> = dangerous.iter().map(|&(a, _)| a.as_str()).collect();
+
+    sorted.join(",")
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let foods = read_input(reader)?;
+
+        let candidates = map_candidates(foods);
+
+        let allergens = find_allergens(&candidates)?;
+
+        Ok(canonical_dangerous_list(&allergens))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_dangerous_list() {
+        let foods = read_input(get_test_input().as_bytes()).unwrap();
+
+        let candidates = map_candidates(foods);
+
+        let allergens = find_allergens(&candidates).unwrap();
+
+        assert_eq!(canonical_dangerous_list(&allergens), "mxmxvkd,sqjhc,fvjkl");
+    }
+
+    #[test]
+    fn test_solver() {
+        let answer = Solver.solve(&mut get_test_input().as_bytes()).unwrap();
+        assert_eq!(answer, "mxmxvkd,sqjhc,fvjkl");
+    }
+
+    fn get_test_input() -> String {
+        String::from(
+            "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)",
+        )
+    }
+}
@@ -1,58 +1,27 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
 use std::collections::VecDeque;
-use std::env;
-use std::fs::File;
-use std::io;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::io::Read;
-use std::path::Path;
 
-fn open_input(filename: &str) -> io::Result<File> {
-    let path = Path::new(filename);
-    File::open(path)
-}
-
-fn read_input(reader: impl Read) -> Result<(VecDeque<i64>, VecDeque<i64>), String> {
-    let reader = BufReader::new(reader);
-
-    let mut stack_1: VecDeque<i64> = VecDeque::new();
-    let mut stack_2: VecDeque<i64> = VecDeque::new();
-
-    let mut lines_iter = reader.lines();
-
-    while let Some(line) = lines_iter.next() {
-        match line {
-            Ok(x) => {
-                if x.is_empty() {
-                    break;
-                }
-
-                if let Ok(n) = x.parse() {
-                    stack_1.push_back(n);
-                }
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
+fn read_input(reader: impl Read) -> Result<(VecDeque<i64>, VecDeque<i64>), InputError> {
+    let groups = input::read_groups(reader)?;
+    if groups.len() != 2 {
+        return Err(InputError::invalid(format!(
+            "expected 2 players, found {}",
+            groups.len()
+        )));
     }
 
-    while let Some(line) = lines_iter.next() {
-        match line {
-            Ok(x) => {
-                if x.is_empty() {
-                    break;
-                }
-
-                if let Ok(n) = x.parse() {
-                    stack_2.push_back(n);
-                }
-            }
-            Err(x) => {
-                return Err(format!("cannot read input: {:?}", x));
-            }
-        }
-    }
+    let mut stacks = groups.iter().map(|group| {
+        group
+            .iter()
+            .filter_map(|x| x.parse().ok())
+            .collect::<VecDeque<i64>>()
+    });
+
+    let stack_1 = stacks.next().unwrap();
+    let stack_2 = stacks.next().unwrap();
 
     Ok((stack_1, stack_2))
 }
@@ -85,13 +54,11 @@ impl Game {
     }
 
     fn winning_score(&self) -> i64 {
-        let full_stack: &VecDeque<i64>;
-
-        if self.stack_1.is_empty() {
-            full_stack = &self.stack_2;
+        let full_stack = if self.stack_1.is_empty() {
+            &self.stack_2
         } else {
-            full_stack = &self.stack_1;
-        }
+            &self.stack_1
+        };
 
         full_stack
             .iter()
@@ -101,25 +68,19 @@ impl Game {
     }
 }
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    let filename = args.get(1).unwrap();
+pub struct Solver;
 
-    let input_file = open_input(&filename).unwrap();
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let (stack_1, stack_2) = read_input(reader)?;
 
-    let (stack_1, stack_2) = read_input(input_file).unwrap();
-
-    println!("stack_1 {:?}", stack_1);
-    println!("stack_2 {:?}", stack_2);
+        let mut game = Game::new(stack_1, stack_2);
+        while !game.is_finished() {
+            game.play_round();
+        }
 
-    let mut game = Game::new(stack_1, stack_2);
-    while !game.is_finished() {
-        game.play_round();
+        Ok(game.winning_score().to_string())
     }
-
-    let score = game.winning_score();
-    println!("{:?}", score);
 }
 
 #[cfg(test)]
@@ -0,0 +1,301 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+
+const MOVES: usize = 100;
+// every move picks up three cups and needs a destination cup other than the
+// current one
+const MIN_CUPS: usize = 5;
+
+#[derive(PartialEq, Debug)]
+pub(crate) struct Game {
+    pub(crate) turn: usize,
+    // next[label] is the label of the cup clockwise of the cup `label`,
+    // index 0 is unused as labels start from 1
+    next: Vec<usize>,
+    current: usize,
+}
+
+impl Game {
+    pub(crate) fn new(input: String) -> Result<Self, InputError> {
+        Self::new_with_len(&input, input.len())
+    }
+
+    /// Starts a game with the given cup labels followed by cups labeled
+    /// from one above the highest label up to `total_cups`.
+    pub(crate) fn new_with_len(labels: &str, total_cups: usize) -> Result<Self, InputError> {
+        let mut cups: Vec<usize> = labels
+            .chars()
+            .map(|x| x.to_digit(10).unwrap() as usize)
+            .collect();
+
+        let max_label = cups.iter().copied().max().unwrap_or(0);
+        cups.extend(max_label + 1..=total_cups);
+        if cups.len() < MIN_CUPS {
+            return Err(too_few_cups(cups.len()));
+        }
+
+        let mut next = vec![0; cups.len() + 1];
+        for (i, &cup) in cups.iter().enumerate() {
+            next[cup] = cups[(i + 1) % cups.len()];
+        }
+
+        Ok(Self {
+            turn: 1,
+            next,
+            current: cups[0],
+        })
+    }
+
+    fn previous_label(&self, label: usize) -> usize {
+        if label == 1 {
+            self.next.len() - 1
+        } else {
+            label - 1
+        }
+    }
+
+    pub(crate) fn play_round(&mut self) {
+        let pick_1 = self.next[self.current];
+        let pick_2 = self.next[pick_1];
+        let pick_3 = self.next[pick_2];
+
+        // take the picked cups out of the circle
+        self.next[self.current] = self.next[pick_3];
+
+        let mut destination = self.previous_label(self.current);
+        while destination == pick_1 || destination == pick_2 || destination == pick_3 {
+            destination = self.previous_label(destination);
+        }
+
+        // and put them back after the destination cup
+        self.next[pick_3] = self.next[destination];
+        self.next[destination] = pick_1;
+
+        self.current = self.next[self.current];
+
+        self.turn += 1;
+    }
+
+    // cup labels clockwise, starting from the current cup
+    #[cfg(test)]
+    pub(crate) fn cups(&self) -> Vec<usize> {
+        let mut cups = Vec::with_capacity(self.next.len() - 1);
+        let mut cup = self.current;
+        loop {
+            cups.push(cup);
+            cup = self.next[cup];
+            if cup == self.current {
+                break;
+            }
+        }
+        cups
+    }
+
+    fn get_answer(&self) -> usize {
+        let mut answer = 0;
+        let mut cup = self.next[1];
+        while cup != 1 {
+            answer = answer * 10 + cup;
+            cup = self.next[cup];
+        }
+        answer
+    }
+
+    /// The two cups immediately clockwise of cup 1.
+    pub(crate) fn cups_after_one(&self) -> (usize, usize) {
+        let first = self.next[1];
+        (first, self.next[first])
+    }
+}
+
+fn too_few_cups(cups: usize) -> InputError {
+    InputError::invalid(format!(
+        "{} cups, the game needs at least {}",
+        cups, MIN_CUPS
+    ))
+}
+
+/// Checks that the labels are the digits from 1 up to the number of cups,
+/// each used once, e.g. "389125467".
+pub(crate) fn parse_labels(labels: &str) -> Result<String, InputError> {
+    let labels = labels.trim();
+
+    let mut seen = [false; 10];
+    for c in labels.chars() {
+        let label = c
+            .to_digit(10)
+            .filter(|&d| d >= 1 && d as usize <= labels.len())
+            .ok_or_else(|| InputError::invalid(format!("invalid cup label {:?}", c)))?;
+        if seen[label as usize] {
+            return Err(InputError::invalid(format!("cup {} appears twice", label)));
+        }
+        seen[label as usize] = true;
+    }
+
+    if labels.is_empty() {
+        return Err(InputError::invalid("no cup labels"));
+    }
+    if labels.len() < MIN_CUPS {
+        return Err(too_few_cups(labels.len()));
+    }
+
+    Ok(labels.to_owned())
+}
+
+pub(crate) fn read_input(reader: impl Read) -> Result<String, InputError> {
+    let lines = input::read_lines(reader)?;
+
+    let (i, line) = lines
+        .iter()
+        .enumerate()
+        .find(|(_, l)| !l.trim().is_empty())
+        .ok_or_else(|| InputError::invalid("no cup labels"))?;
+
+    parse_labels(line).map_err(|err| InputError::parse(i + 1, err.to_string()))
+}
+
+/// Cup labels from `--cups` if given, otherwise from the input.
+pub(crate) fn cup_labels(reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+    match options.get("cups") {
+        Some(labels) => parse_labels(labels),
+        None => read_input(reader),
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        let mut game = Game::new(cup_labels(reader, options)?)?;
+        let moves = options.parse_or("moves", MOVES)?;
+
+        while game.turn <= moves {
+            game.play_round();
+        }
+
+        Ok(game.get_answer().to_string())
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["cups", "moves"]
+    }
+
+    fn needs_input(&self, options: &Options) -> bool {
+        !options.contains("cups")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_new() {
+        let actual = Game::new(String::from("219347865")).unwrap();
+
+        assert_eq!(actual.cups(), vec![2, 1, 9, 3, 4, 7, 8, 6, 5]);
+        assert_eq!(actual.turn, 1);
+        assert_eq!(actual.current, 2);
+    }
+
+    #[test]
+    fn test_play_game() {
+        let mut game = Game::new(String::from("389125467")).unwrap();
+
+        assert_eq!(game.cups(), vec![3, 8, 9, 1, 2, 5, 4, 6, 7]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![2, 8, 9, 1, 5, 4, 6, 7, 3]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![5, 4, 6, 7, 8, 9, 1, 3, 2]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![8, 9, 1, 3, 4, 6, 7, 2, 5]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![4, 6, 7, 9, 1, 3, 2, 5, 8]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![1, 3, 6, 7, 9, 2, 5, 8, 4]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![9, 3, 6, 7, 2, 5, 8, 4, 1]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![2, 5, 8, 3, 6, 7, 4, 1, 9]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![6, 7, 4, 1, 5, 8, 3, 9, 2]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![5, 7, 4, 1, 8, 3, 9, 2, 6]);
+        game.play_round();
+
+        assert_eq!(game.cups(), vec![8, 3, 7, 4, 1, 9, 2, 6, 5]);
+
+        assert_eq!(game.get_answer(), 92658374);
+
+        while game.turn <= MOVES {
+            game.play_round();
+        }
+
+        assert_eq!(game.get_answer(), 67384529);
+    }
+
+    #[test]
+    fn test_read_input() {
+        assert_eq!(read_input("389125467\n".as_bytes()).unwrap(), "389125467");
+
+        match read_input("\n38912546x".as_bytes()) {
+            Err(InputError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse_labels("3891254670").is_err());
+        assert!(parse_labels("1231").is_err());
+        assert!(parse_labels("124").is_err());
+        assert!(parse_labels("").is_err());
+    }
+
+    #[test]
+    fn test_too_few_cups() {
+        match read_input("1234\n".as_bytes()) {
+            Err(InputError::Parse { line, message }) => {
+                assert_eq!(line, 1);
+                assert_eq!(message, "invalid input: 4 cups, the game needs at least 5");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(parse_labels("12345").is_ok());
+
+        for cups in ["1", "123"] {
+            let mut options = Options::new();
+            options.set("cups", cups);
+            assert!(Solver.solve_with(&mut "".as_bytes(), &options).is_err());
+        }
+
+        assert!(Game::new_with_len("21", 4).is_err());
+        assert!(Game::new_with_len("21", 5).is_ok());
+    }
+
+    #[test]
+    fn test_solve_with_options() {
+        let mut options = Options::new();
+        options.set("cups", "389125467");
+        options.set("moves", "10");
+
+        let answer = Solver.solve_with(&mut "".as_bytes(), &options).unwrap();
+        assert_eq!(answer, "92658374");
+        assert!(!Solver.needs_input(&options));
+
+        options.set("moves", "-1");
+        assert!(Solver.solve_with(&mut "".as_bytes(), &options).is_err());
+    }
+}
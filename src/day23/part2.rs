@@ -0,0 +1,67 @@
+use crate::day23::part1::cup_labels;
+use crate::day23::part1::Game;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::io::Read;
+
+const TOTAL_CUPS: usize = 1_000_000;
+const MOVES: usize = 10_000_000;
+
+fn play(labels: &str, moves: usize) -> Result<usize, InputError> {
+    let mut game = Game::new_with_len(labels, TOTAL_CUPS)?;
+
+    while game.turn <= moves {
+        game.play_round();
+    }
+
+    let (first, second) = game.cups_after_one();
+    Ok(first * second)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        let labels = cup_labels(reader, options)?;
+        let moves = options.parse_or("moves", MOVES)?;
+
+        Ok(play(&labels, moves)?.to_string())
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["cups", "moves"]
+    }
+
+    fn needs_input(&self, options: &Options) -> bool {
+        !options.contains("cups")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_with_len() {
+        let mut game = Game::new_with_len("389125467", 12).unwrap();
+
+        assert_eq!(game.cups(), vec![3, 8, 9, 1, 2, 5, 4, 6, 7, 10, 11, 12]);
+
+        game.play_round();
+        assert_eq!(game.cups(), vec![2, 8, 9, 1, 5, 4, 6, 7, 10, 11, 12, 3]);
+
+        game.play_round();
+        assert_eq!(game.cups(), vec![5, 4, 6, 7, 10, 11, 12, 8, 9, 1, 3, 2]);
+        assert_eq!(game.cups_after_one(), (3, 2));
+    }
+
+    #[test]
+    fn test_play() {
+        assert_eq!(play("389125467", MOVES).unwrap(), 149245887792);
+    }
+}
@@ -0,0 +1,383 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Sub;
+
+/// The six neighbours of a hexagon on a grid of rows, as in the lobby floor.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl Direction {
+    /// Every direction, clockwise from east.
+    pub const ALL: [Direction; 6] = [
+        Direction::East,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+        Direction::NorthEast,
+    ];
+
+    /// The neighbour of the origin in this direction.
+    pub fn offset(self) -> Hex {
+        match self {
+            Direction::East => Hex::new(1, 0),
+            Direction::SouthEast => Hex::new(0, 1),
+            Direction::SouthWest => Hex::new(-1, 1),
+            Direction::West => Hex::new(-1, 0),
+            Direction::NorthWest => Hex::new(0, -1),
+            Direction::NorthEast => Hex::new(1, -1),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::East => "e",
+            Direction::SouthEast => "se",
+            Direction::SouthWest => "sw",
+            Direction::West => "w",
+            Direction::NorthWest => "nw",
+            Direction::NorthEast => "ne",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A hexagon in axial coordinates: `q` grows to the east and `r` to the
+/// south east, so every row of hexagons has the same `r`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, PartialOrd, Ord)]
+pub struct Hex {
+    q: i64,
+    r: i64,
+}
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    pub fn new(q: i64, r: i64) -> Self {
+        Hex { q, r }
+    }
+
+    pub fn axial(self) -> (i64, i64) {
+        (self.q, self.r)
+    }
+
+    /// Cube coordinates (x, y, z) with x + y + z = 0, None if they do not
+    /// add up.
+    pub fn from_cube(x: i64, y: i64, z: i64) -> Option<Self> {
+        if x + y + z == 0 {
+            Some(Hex::new(x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn cube(self) -> (i64, i64, i64) {
+        (self.q, self.r, -self.q - self.r)
+    }
+
+    /// Offset coordinates (column, row) with every odd row shifted half a
+    /// hexagon to the east.
+    pub fn from_offset(column: i64, row: i64) -> Self {
+        Hex::new(column - row.div_euclid(2), row)
+    }
+
+    pub fn offset(self) -> (i64, i64) {
+        (self.q + self.r.div_euclid(2), self.r)
+    }
+
+    pub fn neighbour(self, direction: Direction) -> Hex {
+        self + direction.offset()
+    }
+
+    /// The six neighbours in the order of `Direction::ALL`.
+    pub fn neighbours(self) -> [Hex; 6] {
+        Direction::ALL.map(|direction| self.neighbour(direction))
+    }
+
+    /// Centre of the hexagon when drawn with pointy tops and corners `size`
+    /// away from the centre, with y growing downwards.
+    pub fn to_pixel(self, size: f64) -> (f64, f64) {
+        let x = size * 3f64.sqrt() * (self.q as f64 + self.r as f64 / 2.0);
+        let y = size * 1.5 * self.r as f64;
+        (x, y)
+    }
+
+    /// Corners of the hexagon drawn as in `to_pixel`, clockwise from the top.
+    pub fn corners(self, size: f64) -> [(f64, f64); 6] {
+        let (x, y) = self.to_pixel(size);
+        let mut corners = [(0.0, 0.0); 6];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let angle = (60.0 * i as f64 - 90.0).to_radians();
+            *corner = (x + size * angle.cos(), y + size * angle.sin());
+        }
+        corners
+    }
+
+    /// Number of steps between the two hexagons.
+    pub fn distance(self, other: Hex) -> i64 {
+        let (x, y, z) = (self - other).cube();
+        (x.abs() + y.abs() + z.abs()) / 2
+    }
+
+    /// The hexagons exactly `radius` steps away, clockwise from the one
+    /// to the south west.
+    pub fn ring(self, radius: i64) -> Vec<Hex> {
+        if radius <= 0 {
+            return vec![self];
+        }
+
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut hex = self + Direction::SouthWest.offset() * radius;
+        // walk the six edges, starting up the one to the west corner
+        for direction in [
+            Direction::NorthWest,
+            Direction::NorthEast,
+            Direction::East,
+            Direction::SouthEast,
+            Direction::SouthWest,
+            Direction::West,
+        ] {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex.neighbour(direction);
+            }
+        }
+        ring
+    }
+}
+
+impl Add for Hex {
+    type Output = Hex;
+
+    fn add(self, other: Hex) -> Hex {
+        Hex::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Hex;
+
+    fn sub(self, other: Hex) -> Hex {
+        Hex::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Hex;
+
+    fn mul(self, n: i64) -> Hex {
+        Hex::new(self.q * n, self.r * n)
+    }
+}
+
+/// Why a path could not be parsed, with the column of the offending
+/// character counting from 1.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PathError {
+    pub column: usize,
+    pub found: Option<char>,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.found {
+            Some(c) => write!(f, "column {}: unexpected {:?}", self.column, c),
+            None => write!(
+                f,
+                "column {}: path ends in the middle of a step",
+                self.column
+            ),
+        }
+    }
+}
+
+impl Error for PathError {}
+
+/// Parses directions written without separators, e.g. "esenee".
+pub fn parse_path(path: &str) -> Result<Vec<Direction>, PathError> {
+    let mut directions = Vec::new();
+
+    let mut chars = path.chars().enumerate();
+    while let Some((i, c)) = chars.next() {
+        let direction = match c {
+            'e' => Direction::East,
+            'w' => Direction::West,
+            's' | 'n' => match chars.next() {
+                Some((_, 'e')) if c == 's' => Direction::SouthEast,
+                Some((_, 'w')) if c == 's' => Direction::SouthWest,
+                Some((_, 'e')) => Direction::NorthEast,
+                Some((_, 'w')) => Direction::NorthWest,
+                Some((j, other)) => {
+                    return Err(PathError {
+                        column: j + 1,
+                        found: Some(other),
+                    })
+                }
+                None => {
+                    return Err(PathError {
+                        column: i + 2,
+                        found: None,
+                    })
+                }
+            },
+            other => {
+                return Err(PathError {
+                    column: i + 1,
+                    found: Some(other),
+                })
+            }
+        };
+        directions.push(direction);
+    }
+
+    Ok(directions)
+}
+
+/// Where following `path` from the origin ends up.
+pub fn walk(path: &[Direction]) -> Hex {
+    path.iter()
+        .fold(Hex::ORIGIN, |hex, &direction| hex.neighbour(direction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path("esenee").unwrap(),
+            vec![
+                Direction::East,
+                Direction::SouthEast,
+                Direction::NorthEast,
+                Direction::East
+            ]
+        );
+        assert_eq!(walk(&parse_path("nwwswee").unwrap()), Hex::ORIGIN);
+        assert_eq!(walk(&parse_path("esew").unwrap()), Hex::new(0, 1));
+
+        let err = parse_path("eesx").unwrap_err();
+        assert_eq!(
+            err,
+            PathError {
+                column: 4,
+                found: Some('x')
+            }
+        );
+        assert_eq!(err.to_string(), "column 4: unexpected 'x'");
+        assert_eq!(
+            parse_path("wq").unwrap_err(),
+            PathError {
+                column: 2,
+                found: Some('q')
+            }
+        );
+        assert_eq!(parse_path("en").unwrap_err().column, 3);
+        assert_eq!(parse_path("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let hex = Hex::new(2, -3);
+
+        assert_eq!(hex.cube(), (2, -3, 1));
+        assert_eq!(Hex::from_cube(2, -3, 1), Some(hex));
+        assert_eq!(Hex::from_cube(2, -3, 2), None);
+
+        for r in -3..=3 {
+            for q in -3..=3 {
+                let hex = Hex::new(q, r);
+                let (column, row) = hex.offset();
+                assert_eq!(Hex::from_offset(column, row), hex);
+            }
+        }
+        // odd rows are shifted east, so the south east neighbour of an even
+        // row hexagon is in the same column
+        assert_eq!(
+            Hex::new(0, 0).neighbour(Direction::SouthEast).offset(),
+            (0, 1)
+        );
+        assert_eq!(
+            Hex::new(0, 1).neighbour(Direction::SouthEast).offset(),
+            (1, 2)
+        );
+        assert_eq!(Hex::new(0, -1).offset(), (-1, -1));
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(Hex::ORIGIN.distance(Hex::ORIGIN), 0);
+        for neighbour in Hex::new(5, -2).neighbours().iter() {
+            assert_eq!(neighbour.distance(Hex::new(5, -2)), 1);
+        }
+        assert_eq!(Hex::new(3, 0).distance(Hex::new(0, 3)), 3);
+        assert_eq!(Hex::new(-2, -1).distance(Hex::new(1, 1)), 5);
+    }
+
+    #[test]
+    fn test_ring() {
+        let centre = Hex::new(1, -1);
+
+        assert_eq!(centre.ring(0), vec![centre]);
+
+        let mut ring = centre.ring(1);
+        ring.sort();
+        let mut neighbours = centre.neighbours().to_vec();
+        neighbours.sort();
+        assert_eq!(ring, neighbours);
+
+        let ring = centre.ring(3);
+        assert_eq!(ring.len(), 18);
+        assert!(ring.iter().all(|hex| hex.distance(centre) == 3));
+        for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+            assert_eq!(a.distance(*b), 1);
+        }
+    }
+
+    #[test]
+    fn test_geometry() {
+        let size = 10.0;
+        let close = |a: (f64, f64), b: (f64, f64), distance: f64| {
+            ((a.0 - b.0).hypot(a.1 - b.1) - distance).abs() < 1e-9
+        };
+
+        assert_eq!(Hex::ORIGIN.to_pixel(size), (0.0, 0.0));
+        for neighbour in Hex::ORIGIN.neighbours().iter() {
+            assert!(close(
+                neighbour.to_pixel(size),
+                (0.0, 0.0),
+                size * 3f64.sqrt()
+            ));
+        }
+        // rows are straight lines
+        assert_eq!(
+            Hex::new(2, 1).to_pixel(size).1,
+            Hex::new(-3, 1).to_pixel(size).1
+        );
+
+        let corners = Hex::new(1, 2).corners(size);
+        let centre = Hex::new(1, 2).to_pixel(size);
+        assert!(close(corners[0], (centre.0, centre.1 - size), 0.0));
+        for i in 0..6 {
+            assert!(close(corners[i], centre, size));
+            assert!(close(corners[i], corners[(i + 1) % 6], size));
+        }
+
+        // neighbours share an edge
+        let east = Hex::new(1, 2).neighbour(Direction::East).corners(size);
+        assert!(close(corners[1], east[5], 0.0));
+        assert!(close(corners[2], east[4], 0.0));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod hex;
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,97 @@
+use crate::day24::hex;
+use crate::day24::hex::Direction;
+use crate::day24::hex::Hex;
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::io::Read;
+
+pub(crate) fn read_input(reader: impl Read) -> Result<Vec<Vec<Direction>>, InputError> {
+    input::read_lines(reader)?
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            hex::parse_path(line).map_err(|err| InputError::parse(i + 1, err.to_string()))
+        })
+        .collect()
+}
+
+/// The tiles left black after flipping the tile at the end of every path.
+pub(crate) fn flip_tiles(paths: &[Vec<Direction>]) -> HashSet<Hex> {
+    let mut flipped: HashSet<Hex> = HashSet::new();
+
+    for path in paths {
+        let tile = hex::walk(path);
+        if !flipped.remove(&tile) {
+            flipped.insert(tile);
+        }
+    }
+
+    flipped
+}
+
+/// The black tiles after following every line of the input.
+pub(crate) fn read_tiles(reader: &mut dyn Read) -> Result<HashSet<Hex>, InputError> {
+    Ok(flip_tiles(&read_input(reader)?))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        Ok(read_tiles(reader)?.len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_rules() {
+        let test_input = get_test_input();
+
+        let moves = read_input(test_input.as_bytes()).unwrap();
+        println!("moves {:?}", moves);
+
+        assert_eq!(moves.len(), 20);
+        assert_eq!(flip_tiles(&moves).len(), 10);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        match read_input("esew\nnwwxwee".as_bytes()) {
+            Err(InputError::Parse { line, message }) => {
+                assert_eq!(line, 2);
+                assert_eq!(message, "column 4: unexpected 'x'");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    fn get_test_input() -> String {
+        String::from(
+            "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew",
+        )
+    }
+}
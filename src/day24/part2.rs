@@ -0,0 +1,210 @@
+use crate::automaton::Automaton;
+use crate::automaton::Rule;
+use crate::automaton::Sparse;
+use crate::day24::hex::Hex;
+use crate::day24::part1::read_tiles;
+use crate::input::InputError;
+use crate::render::Animation;
+use crate::render::Frame;
+use crate::render::Rgb;
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::io::Read;
+
+fn neighbours(hex: &Hex) -> [Hex; 6] {
+    hex.neighbours()
+}
+
+// black tiles with one or two black neighbours stay black, white tiles with
+// exactly two black neighbours are flipped to black
+fn rule() -> Rule {
+    Rule::new(vec![2], vec![1, 2])
+}
+
+pub(crate) fn floor(blacks: HashSet<Hex>) -> Sparse<Hex, fn(&Hex) -> [Hex; 6]> {
+    Sparse::new(blacks, neighbours, rule())
+}
+
+/// Colours of white and black tiles when drawn.
+pub(crate) const PALETTE: [Rgb; 2] = [[240, 240, 232], [24, 24, 24]];
+
+// tiles are two pixels wide, each row shifted by half a tile from the one
+// above it
+fn pixel(hex: &Hex) -> (i64, i64) {
+    let (q, r) = hex.axial();
+    (2 * q + r, r)
+}
+
+/// Every day of black tiles drawn on frames covering all of them.
+pub(crate) fn animation(days: &[HashSet<Hex>]) -> Animation {
+    let pixels = days.iter().flatten().map(pixel);
+    let (min_x, min_y, max_x, max_y) =
+        pixels.fold((0, 0, 1, 0), |(min_x, min_y, max_x, max_y), (x, y)| {
+            (min_x.min(x), min_y.min(y), max_x.max(x + 1), max_y.max(y))
+        });
+
+    let frames = days
+        .iter()
+        .map(|blacks| {
+            let mut frame = Frame::new((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+            for tile in blacks {
+                let (x, y) = pixel(tile);
+                let (x, y) = ((x - min_x) as usize, (y - min_y) as usize);
+                frame.set(x, y, 1);
+                frame.set(x + 1, y, 1);
+            }
+            frame
+        })
+        .collect();
+
+    Animation {
+        palette: PALETTE.to_vec(),
+        frames,
+    }
+}
+
+/// The black tiles at the start and after each of the given number of days.
+pub(crate) fn days(blacks: HashSet<Hex>, days: usize) -> Vec<HashSet<Hex>> {
+    let mut floor = floor(blacks);
+    let mut tiles = vec![floor.live().clone()];
+    for _ in 0..days {
+        floor.step();
+        tiles.push(floor.live().clone());
+    }
+    tiles
+}
+
+const TILE_SIZE: f64 = 10.0;
+
+fn colour([r, g, b]: Rgb) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// The floor as an SVG image. Every tile in the rows and columns reached by
+/// any of `extent` is drawn, with a margin of one tile, so that images of
+/// different days line up.
+pub(crate) fn svg(blacks: &HashSet<Hex>, extent: &[HashSet<Hex>]) -> String {
+    let offsets = extent.iter().flatten().map(|tile| tile.offset());
+    let (min_column, min_row, max_column, max_row) = offsets.fold(
+        (0, 0, 0, 0),
+        |(min_column, min_row, max_column, max_row), (column, row)| {
+            (
+                min_column.min(column),
+                min_row.min(row),
+                max_column.max(column),
+                max_row.max(row),
+            )
+        },
+    );
+
+    let mut tiles = String::new();
+    let (mut left, mut top, mut right, mut bottom) = (f64::MAX, f64::MAX, f64::MIN, f64::MIN);
+    for row in min_row - 1..=max_row + 1 {
+        for column in min_column - 1..=max_column + 1 {
+            let tile = Hex::from_offset(column, row);
+            let corners = tile.corners(TILE_SIZE);
+            for &(x, y) in corners.iter() {
+                left = left.min(x);
+                top = top.min(y);
+                right = right.max(x);
+                bottom = bottom.max(y);
+            }
+
+            let points: Vec<String> = corners
+                .iter()
+                .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+                .collect();
+            let class = if blacks.contains(&tile) { "b" } else { "w" };
+            tiles.push_str(&format!(
+                "<polygon class=\"{}\" points=\"{}\"/>\n",
+                class,
+                points.join(" ")
+            ));
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{:.2} {:.2} {:.2} {:.2}\">\n\
+         <style>.w{{fill:{}}}.b{{fill:{}}}polygon{{stroke:#888;stroke-width:0.5}}</style>\n\
+         <title>{} black tiles</title>\n{}</svg>\n",
+        left,
+        top,
+        right - left,
+        bottom - top,
+        colour(PALETTE[0]),
+        colour(PALETTE[1]),
+        blacks.len(),
+        tiles
+    )
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        let mut floor = floor(read_tiles(reader)?);
+        floor.run(100);
+
+        Ok(floor.population().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days() {
+        let black_tiles = read_tiles(&mut get_test_input().as_bytes()).unwrap();
+
+        let days = days(black_tiles, 3);
+        let counts: Vec<usize> = days.iter().map(|blacks| blacks.len()).collect();
+        assert_eq!(counts, vec![10, 15, 12, 25]);
+    }
+
+    fn get_test_input() -> String {
+        String::from(
+            "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew",
+        )
+    }
+
+    #[test]
+    fn test_svg() {
+        let days = days(read_tiles(&mut get_test_input().as_bytes()).unwrap(), 3);
+        assert_eq!(days.len(), 4);
+        assert_eq!(days[3].len(), 25);
+
+        let image = svg(&days[3], &days);
+        assert!(image.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\""));
+        assert!(image.ends_with("</svg>\n"));
+        assert!(image.contains("<title>25 black tiles</title>"));
+        assert_eq!(image.matches("class=\"b\"").count(), 25);
+
+        // every day is drawn on the same tiles
+        let first = svg(&days[0], &days);
+        assert_eq!(
+            first.matches("<polygon").count(),
+            image.matches("<polygon").count()
+        );
+        assert_eq!(first.matches("class=\"b\"").count(), 10);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::input;
+use crate::input::InputError;
+use crate::solution::Options;
+use crate::solution::Solution;
+use std::collections::HashMap;
+use std::io::Read;
+
+const MODULUS: u64 = 20201227;
+const SUBJECT: u64 = 7;
+// the baby step table holds sqrt(modulus) entries
+const MAX_MODULUS: u64 = 1 << 40;
+
+// card and door public keys
+fn read_input(reader: impl Read) -> Result<(u64, u64), InputError> {
+    let keys: Vec<u64> = input::read_parsed(reader)?;
+
+    match keys[..] {
+        [card, door] => Ok((card, door)),
+        _ => Err(InputError::invalid(format!(
+            "expected 2 public keys, found {}",
+            keys.len()
+        ))),
+    }
+}
+
+fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut exp = exp;
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+// inverse of a modulo modulus via the extended euclidean algorithm
+fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    let (mut old_r, mut r) = (a as i128 % modulus as i128, modulus as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+
+    while r != 0 {
+        let q = old_r / r;
+        let (next_r, next_s) = (old_r - q * r, old_s - q * s);
+        old_r = r;
+        r = next_r;
+        old_s = s;
+        s = next_s;
+    }
+
+    if old_r != 1 {
+        return None;
+    }
+
+    Some(old_s.rem_euclid(modulus as i128) as u64)
+}
+
+/// Finds the smallest `x` for which `subject^x = target (mod modulus)` using
+/// baby-step giant-step, in O(sqrt(modulus)) time and memory. The subject
+/// must be coprime to the modulus, otherwise there is no giant step and the
+/// result is None even if such an `x` exists.
+fn discrete_log(subject: u64, target: u64, modulus: u64) -> Option<u64> {
+    if modulus == 1 {
+        return Some(0);
+    }
+
+    let target = target % modulus;
+    let steps = (modulus as f64).sqrt().ceil() as u64;
+
+    // baby steps: subject^j for j < steps
+    let mut baby_steps: HashMap<u64, u64> = HashMap::new();
+    let mut value = 1;
+    for j in 0..steps {
+        baby_steps.entry(value).or_insert(j);
+        value = (value as u128 * subject as u128 % modulus as u128) as u64;
+    }
+
+    // giant steps: target * subject^(-steps * i)
+    let giant_step = mod_pow(mod_inverse(subject, modulus)?, steps, modulus);
+    let mut gamma = target;
+    for i in 0..steps {
+        if let Some(j) = baby_steps.get(&gamma) {
+            return Some(i * steps + j);
+        }
+        gamma = (gamma as u128 * giant_step as u128 % modulus as u128) as u64;
+    }
+
+    None
+}
+
+fn find_encryption_key(card_key: u64, door_key: u64, subject: u64, modulus: u64) -> Option<u64> {
+    let card_loops = discrete_log(subject, card_key, modulus)?;
+
+    Some(mod_pow(door_key, card_loops, modulus))
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError> {
+        self.solve_with(reader, &Options::new())
+    }
+
+    fn solve_with(&self, reader: &mut dyn Read, options: &Options) -> Result<String, InputError> {
+        let (card_key, door_key) = read_input(reader)?;
+        let subject = options.parse_or("subject", SUBJECT)?;
+        let modulus = options.parse_or("modulus", MODULUS)?;
+        if !(2..=MAX_MODULUS).contains(&modulus) {
+            return Err(InputError::invalid(format!(
+                "--modulus \"{}\": must be between 2 and {}",
+                modulus, MAX_MODULUS
+            )));
+        }
+        if mod_inverse(subject, modulus).is_none() {
+            return Err(InputError::invalid(format!(
+                "--subject \"{}\": must be coprime to the modulus {}",
+                subject, modulus
+            )));
+        }
+
+        find_encryption_key(card_key, door_key, subject, modulus)
+            .map(|key| key.to_string())
+            .ok_or_else(|| InputError::invalid("no loop size produces the card public key"))
+    }
+
+    fn options(&self) -> &'static [&'static str] {
+        &["subject", "modulus"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_input() {
+        assert_eq!(
+            read_input("5764801\n17807724\n".as_bytes()).unwrap(),
+            (5764801, 17807724)
+        );
+        assert!(read_input("5764801\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_get_encryption() {
+        let door_loops = discrete_log(SUBJECT, 17807724, MODULUS).unwrap();
+        assert_eq!(door_loops, 11);
+
+        let card_loops = discrete_log(SUBJECT, 5764801, MODULUS).unwrap();
+        assert_eq!(card_loops, 8);
+
+        assert_eq!(mod_pow(17807724, card_loops, MODULUS), 14897079);
+        assert_eq!(mod_pow(5764801, door_loops, MODULUS), 14897079);
+
+        assert_eq!(
+            find_encryption_key(5764801, 17807724, SUBJECT, MODULUS),
+            Some(14897079)
+        );
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(7, 0, MODULUS), 1);
+        assert_eq!(mod_pow(7, 8, MODULUS), 5764801);
+        assert_eq!(mod_pow(2, 64, 1_000_000_007), 582344008);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        let modulus = 1_000_000_007;
+        let target = mod_pow(5, 123_456_789, modulus);
+        let x = discrete_log(5, target, modulus).unwrap();
+        assert_eq!(mod_pow(5, x, modulus), target);
+
+        // composite modulus
+        assert_eq!(discrete_log(3, 81, 100), Some(4));
+        assert_eq!(discrete_log(3, 2, 100), None);
+
+        // subject not invertible
+        assert_eq!(discrete_log(10, 3, 100), None);
+    }
+
+    #[test]
+    fn test_solve_with_options() {
+        let mut options = Options::new();
+        assert_eq!(
+            Solver
+                .solve_with(&mut "5764801\n17807724\n".as_bytes(), &options)
+                .unwrap(),
+            "14897079"
+        );
+
+        // card loop size 4 and door loop size 3 with 3^x mod 101
+        options.set("subject", "3");
+        options.set("modulus", "101");
+        assert_eq!(
+            Solver
+                .solve_with(&mut "81\n27\n".as_bytes(), &options)
+                .unwrap(),
+            mod_pow(3, 12, 101).to_string()
+        );
+
+        options.set("modulus", "1");
+        let err = Solver
+            .solve_with(&mut "81\n27\n".as_bytes(), &options)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: --modulus \"1\": must be between 2 and 1099511627776"
+        );
+
+        options.set("modulus", "18446744073709551557");
+        assert!(Solver
+            .solve_with(&mut "81\n27\n".as_bytes(), &options)
+            .is_err());
+
+        options.set("subject", "10");
+        options.set("modulus", "100");
+        let err = Solver
+            .solve_with(&mut "10\n10\n".as_bytes(), &options)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid input: --subject \"10\": must be coprime to the modulus 100"
+        );
+
+        options.set("modulus", "x");
+        assert!(Solver
+            .solve_with(&mut "81\n27\n".as_bytes(), &options)
+            .is_err());
+    }
+}
@@ -0,0 +1,151 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fs::File;
+use std::io;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    Invalid(String),
+}
+
+impl InputError {
+    pub fn parse(line: usize, message: impl Into<String>) -> InputError {
+        InputError::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> InputError {
+        InputError::Invalid(message.into())
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "cannot read input: {}", err),
+            InputError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            InputError::Invalid(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> InputError {
+        InputError::Io(err)
+    }
+}
+
+pub fn open_input(filename: &str) -> Result<File, InputError> {
+    let path = Path::new(filename);
+    Ok(File::open(path)?)
+}
+
+/// Reads every line of the input, without line terminators.
+pub fn read_lines(reader: impl Read) -> Result<Vec<String>, InputError> {
+    let reader = BufReader::new(reader);
+
+    let mut lines = Vec::new();
+    for line in reader.lines() {
+        lines.push(line?);
+    }
+
+    Ok(lines)
+}
+
+/// Reads the input as groups of lines separated by one or more blank lines.
+/// Empty groups are never returned.
+pub fn read_groups(reader: impl Read) -> Result<Vec<Vec<String>>, InputError> {
+    let mut groups = Vec::new();
+    let mut current = Vec::new();
+
+    for line in read_lines(reader)? {
+        if line.is_empty() {
+            if !current.is_empty() {
+                groups.push(current);
+                current = Vec::new();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        groups.push(current);
+    }
+
+    Ok(groups)
+}
+
+/// Parses every non-empty line of the input with `FromStr`.
+pub fn read_parsed<T>(reader: impl Read) -> Result<Vec<T>, InputError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut output = Vec::new();
+    for (i, line) in read_lines(reader)?.iter().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse() {
+            Ok(v) => output.push(v),
+            Err(err) => {
+                return Err(InputError::parse(
+                    i + 1,
+                    format!("invalid value {:?}: {}", line, err),
+                ))
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_lines() {
+        let lines = read_lines("a\nb\n\nc".as_bytes()).unwrap();
+        assert_eq!(lines, vec!["a", "b", "", "c"]);
+    }
+
+    #[test]
+    fn test_read_groups() {
+        let groups = read_groups("a\nb\n\n\nc\n\n".as_bytes()).unwrap();
+        assert_eq!(groups, vec![vec!["a", "b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn test_read_parsed() {
+        let numbers: Vec<i64> = read_parsed("1\n-2\n3\n".as_bytes()).unwrap();
+        assert_eq!(numbers, vec![1, -2, 3]);
+    }
+
+    #[test]
+    fn test_read_parsed_error() {
+        match read_parsed::<i64>("1\nx\n".as_bytes()) {
+            Err(InputError::Parse { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use adventofcode_2020::input;
+use adventofcode_2020::input::InputError;
+use adventofcode_2020::solution;
+use adventofcode_2020::solution::Options;
+use adventofcode_2020::solution::Puzzle;
+use std::env;
+use std::io;
+use std::process;
+use std::time::Instant;
+
+const USAGE: &str = "usage:
+    aoc run <day> <part> [input] [--name value]...
+                                   run one puzzle, input defaults to src/dayNN/input.txt
+    aoc run --all                  run every implemented puzzle with its default input
+    aoc list                       list implemented puzzles and their settings
+
+settings:
+    11 --neighbours adjacent|sight|N --tolerance N
+                                   seats each seat looks at, occupied ones it tolerates
+    14 --decoder v1|v2             docking program decoder version
+    15 --numbers 0,3,6 --turns N   starting numbers instead of the input, turns to play
+    17 --dimensions N --cycles N   dimensions to simulate (2-8), cycles to run
+    18 --arithmetic checked|big    64 bit integers that report overflow or any size
+    19 --rule \"8: 42 | 42 8\"      replace or add a rule, may be given more than once
+    23 --cups 389125467 --moves N  cup labels instead of the input, moves to make
+    25 --subject 7 --modulus N     handshake subject number and modulus";
+
+fn default_input(day: u32) -> String {
+    format!("src/day{:02}/input.txt", day)
+}
+
+fn run_puzzle(
+    puzzle: &Puzzle,
+    filename: Option<&str>,
+    options: &Options,
+) -> Result<String, InputError> {
+    if !puzzle.solution.needs_input(options) {
+        return puzzle.solution.solve_with(&mut io::empty(), options);
+    }
+
+    let filename = match filename {
+        Some(f) => f.to_owned(),
+        None => default_input(puzzle.day),
+    };
+    let mut input_file = input::open_input(&filename)?;
+    puzzle.solution.solve_with(&mut input_file, options)
+}
+
+fn report(puzzle: &Puzzle, filename: Option<&str>, options: &Options) -> bool {
+    let start = Instant::now();
+    let result = run_puzzle(puzzle, filename, options);
+    let elapsed = start.elapsed();
+
+    match result {
+        Ok(answer) => {
+            println!(
+                "day {:2} part {}: {} ({:.3}s)",
+                puzzle.day,
+                puzzle.part,
+                answer,
+                elapsed.as_secs_f64()
+            );
+            true
+        }
+        Err(err) => {
+            eprintln!("day {:2} part {}: error: {}", puzzle.day, puzzle.part, err);
+            false
+        }
+    }
+}
+
+fn parse_number(arg: &str, what: &str) -> Result<u32, String> {
+    arg.parse()
+        .map_err(|_| format!("invalid {} {:?}", what, arg))
+}
+
+// splits the arguments of `run` into positional arguments and settings
+fn parse_run_args(args: &[String]) -> Result<(Vec<&str>, Options, bool), String> {
+    let mut positional = Vec::new();
+    let mut options = Options::new();
+    let mut all = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix("--") {
+            Some("all") => all = true,
+            Some(setting) => match setting.split_once('=') {
+                Some((name, value)) => options.set(name, value),
+                None => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for --{}", setting))?;
+                    options.set(setting, value.as_str());
+                }
+            },
+            None => positional.push(arg.as_str()),
+        }
+    }
+
+    Ok((positional, options, all))
+}
+
+fn check_options(puzzle: &Puzzle, options: &Options) -> Result<(), String> {
+    let accepted = puzzle.solution.options();
+    match options.names().find(|name| !accepted.contains(name)) {
+        Some(name) => Err(format!(
+            "day {} part {} does not take --{}",
+            puzzle.day, puzzle.part, name
+        )),
+        None => Ok(()),
+    }
+}
+
+fn run(args: &[String]) -> Result<bool, String> {
+    match args.first().map(|s| s.as_str()) {
+        Some("list") => {
+            for puzzle in solution::puzzles() {
+                let settings: Vec<String> = puzzle
+                    .solution
+                    .options()
+                    .iter()
+                    .map(|name| format!("--{}", name))
+                    .collect();
+                if settings.is_empty() {
+                    println!("{:02}-{:02}", puzzle.day, puzzle.part);
+                } else {
+                    println!(
+                        "{:02}-{:02} {}",
+                        puzzle.day,
+                        puzzle.part,
+                        settings.join(" ")
+                    );
+                }
+            }
+            Ok(true)
+        }
+        Some("run") => {
+            let (positional, options, all) = parse_run_args(&args[1..])?;
+
+            if all {
+                if !positional.is_empty() || !options.is_empty() {
+                    return Err(USAGE.to_owned());
+                }
+                let mut ok = true;
+                for puzzle in solution::puzzles() {
+                    ok &= report(&puzzle, None, &options);
+                }
+                return Ok(ok);
+            }
+
+            if positional.len() != 2 && positional.len() != 3 {
+                return Err(USAGE.to_owned());
+            }
+            let day = parse_number(positional[0], "day")?;
+            let part = parse_number(positional[1], "part")?;
+            let puzzle = solution::find_puzzle(day, part)
+                .ok_or_else(|| format!("day {} part {} is not implemented", day, part))?;
+            check_options(&puzzle, &options)?;
+            Ok(report(&puzzle, positional.get(2).copied(), &options))
+        }
+        _ => Err(USAGE.to_owned()),
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,647 @@
+use crate::automaton::Automaton;
+use crate::day11;
+use crate::day11::part1::NeighborPolicy;
+use crate::day11::part1::Simulation;
+use crate::day17;
+use crate::day17::part1::Cubes;
+use crate::day24;
+use crate::input::InputError;
+use crate::solution::Options;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+pub type Rgb = [u8; 3];
+
+/// One picture of a simulation, every pixel an index into a palette.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Frame {
+    /// A frame filled with colour 0.
+    pub fn new(width: usize, height: usize) -> Self {
+        Frame {
+            width,
+            height,
+            pixels: vec![0; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: u8) {
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    /// The frame with every pixel blown up to a `scale` by `scale` square.
+    pub fn scaled(&self, scale: usize) -> Frame {
+        let mut scaled = Frame::new(self.width * scale, self.height * scale);
+        for y in 0..scaled.height {
+            for x in 0..scaled.width {
+                scaled.set(x, y, self.get(x / scale, y / scale));
+            }
+        }
+        scaled
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[u8]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+}
+
+/// Every generation of a simulation drawn on frames of the same size.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Animation {
+    pub palette: Vec<Rgb>,
+    pub frames: Vec<Frame>,
+}
+
+/// Days with a simulation that can be rendered.
+pub const DAYS: [u32; 3] = [11, 17, 24];
+
+/// Settings taken by `simulate` for `day`.
+pub fn options(day: u32) -> &'static [&'static str] {
+    match day {
+        11 => &["generations", "neighbours", "tolerance"],
+        17 => &["generations", "dimensions"],
+        24 => &["generations"],
+        _ => &[],
+    }
+}
+
+/// Runs the simulation of `day` on the input and draws every generation.
+/// `--generations` limits how many are run, day 11 runs until nobody moves
+/// by default.
+pub fn simulate(
+    day: u32,
+    reader: &mut dyn Read,
+    options: &Options,
+) -> Result<Animation, InputError> {
+    let generations: Option<usize> = match options.get("generations") {
+        Some(_) => Some(options.parse_or("generations", 0)?),
+        None => None,
+    };
+
+    match day {
+        11 => seats(reader, options, generations),
+        17 => {
+            let plane = day17::part1::read_input(reader)?;
+            let generations = generations.unwrap_or(6);
+            match options.parse_or("dimensions", 3)? {
+                3 => Ok(cubes(Cubes::<3>::new(&plane), generations)),
+                4 => Ok(cubes(Cubes::<4>::new(&plane), generations)),
+                _ => Err(InputError::invalid("only 3 or 4 dimensions can be drawn")),
+            }
+        }
+        24 => {
+            let blacks = day24::part1::read_tiles(reader)?;
+            let days = day24::part2::days(blacks, generations.unwrap_or(100));
+            Ok(day24::part2::animation(&days))
+        }
+        _ => Err(InputError::invalid(format!(
+            "day {} has no simulation to draw",
+            day
+        ))),
+    }
+}
+
+/// The day 24 lobby floor as an SVG image at the start and after every day
+/// up to `--generations`, 100 by default. All images cover the same tiles.
+pub fn floor_svgs(reader: &mut dyn Read, options: &Options) -> Result<Vec<String>, InputError> {
+    let blacks = day24::part1::read_tiles(reader)?;
+    let days = day24::part2::days(blacks, options.parse_or("generations", 100)?);
+
+    Ok(days
+        .iter()
+        .map(|blacks| day24::part2::svg(blacks, &days))
+        .collect())
+}
+
+fn seats(
+    reader: &mut dyn Read,
+    options: &Options,
+    generations: Option<usize>,
+) -> Result<Animation, InputError> {
+    let policy = options.parse_or("neighbours", NeighborPolicy::Adjacent)?;
+    let tolerance = options.parse_or("tolerance", 4)?;
+    let mut simulation = Simulation::new(day11::part1::read_input(reader)?, policy, tolerance);
+
+    let mut frames = vec![simulation.seats().frame()];
+    while generations.is_none_or(|n| frames.len() <= n) && simulation.step() {
+        frames.push(simulation.seats().frame());
+    }
+
+    Ok(Animation {
+        palette: day11::part1::PALETTE.to_vec(),
+        frames,
+    })
+}
+
+fn cubes<const D: usize>(mut cubes: Cubes<D>, generations: usize) -> Animation {
+    let mut states = vec![cubes.clone()];
+    for _ in 0..generations {
+        cubes.step();
+        states.push(cubes.clone());
+    }
+
+    // every frame shows the space the cubes ever reach
+    let mut bounds = ([0; D], [0; D]);
+    for (min, max) in states.iter().filter_map(|cubes| cubes.bounds()) {
+        for axis in 0..D {
+            bounds.0[axis] = bounds.0[axis].min(min[axis]);
+            bounds.1[axis] = bounds.1[axis].max(max[axis]);
+        }
+    }
+
+    Animation {
+        palette: day17::part1::PALETTE.to_vec(),
+        frames: states
+            .iter()
+            .map(|cubes| cubes.frame(bounds.0, bounds.1))
+            .collect(),
+    }
+}
+
+/// Writes the frame as rows of coloured blocks for a terminal with 24-bit
+/// colour.
+pub fn write_ansi(out: &mut impl Write, frame: &Frame, palette: &[Rgb]) -> io::Result<()> {
+    for row in frame.rows() {
+        for &pixel in row {
+            let [r, g, b] = palette[pixel as usize];
+            write!(out, "\x1b[48;2;{};{};{}m  ", r, g, b)?;
+        }
+        writeln!(out, "\x1b[0m")?;
+    }
+    Ok(())
+}
+
+/// Plays the animation in place on a terminal.
+pub fn play_ansi(out: &mut impl Write, animation: &Animation, delay: Duration) -> io::Result<()> {
+    write!(out, "\x1b[2J")?;
+    for (generation, frame) in animation.frames.iter().enumerate() {
+        write!(out, "\x1b[H")?;
+        write_ansi(out, frame, &animation.palette)?;
+        writeln!(out, "generation {}", generation)?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+/// Writes the frame as a binary PPM image.
+pub fn write_ppm(out: &mut impl Write, frame: &Frame, palette: &[Rgb]) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", frame.width, frame.height)?;
+    for &pixel in &frame.pixels {
+        out.write_all(&palette[pixel as usize])?;
+    }
+    Ok(())
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+// zlib stream with the data in uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        stream.push(if blocks.peek().is_none() { 1 } else { 0 });
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn write_png_chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    let mut checked = kind.to_vec();
+    checked.extend_from_slice(data);
+    out.write_all(&checked)?;
+    out.write_all(&crc32(&checked).to_be_bytes())
+}
+
+/// Writes the frame as an indexed colour PNG image. The image data is
+/// stored without compression.
+pub fn write_png(out: &mut impl Write, frame: &Frame, palette: &[Rgb]) -> io::Result<()> {
+    out.write_all(b"\x89PNG\r\n\x1a\n")?;
+
+    let mut header = Vec::new();
+    header.extend_from_slice(&(frame.width as u32).to_be_bytes());
+    header.extend_from_slice(&(frame.height as u32).to_be_bytes());
+    // 8 bits per pixel, indexed colour, no interlacing
+    header.extend_from_slice(&[8, 3, 0, 0, 0]);
+    write_png_chunk(out, b"IHDR", &header)?;
+
+    write_png_chunk(out, b"PLTE", &palette.concat())?;
+
+    let mut scanlines = Vec::with_capacity((frame.width + 1) * frame.height);
+    for row in frame.rows().take(frame.height) {
+        scanlines.push(0);
+        scanlines.extend_from_slice(row);
+    }
+    write_png_chunk(out, b"IDAT", &zlib_stored(&scanlines))?;
+
+    write_png_chunk(out, b"IEND", &[])
+}
+
+const GIF_MAX_CODE: u16 = 4096;
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u32) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+// variable length LZW codes as used in GIF images
+fn gif_lzw(pixels: &[u8], min_size: u32) -> Vec<u8> {
+    let clear: u16 = 1 << min_size;
+    let end = clear + 1;
+
+    let mut out = BitWriter {
+        bytes: Vec::new(),
+        buffer: 0,
+        bits: 0,
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut size = min_size + 1;
+
+    out.write(clear, size);
+    let mut pixels = pixels.iter();
+    let mut prefix = match pixels.next() {
+        Some(&pixel) => pixel as u16,
+        None => {
+            out.write(end, size);
+            return out.finish();
+        }
+    };
+
+    for &pixel in pixels {
+        if let Some(&code) = table.get(&(prefix, pixel)) {
+            prefix = code;
+            continue;
+        }
+
+        out.write(prefix, size);
+        if next == GIF_MAX_CODE {
+            // the table is full, start over
+            out.write(clear, size);
+            table.clear();
+            next = end + 1;
+            size = min_size + 1;
+        } else {
+            table.insert((prefix, pixel), next);
+            next += 1;
+            // the decoder adds its entries one code later
+            if next > 1 << size && size < 12 {
+                size += 1;
+            }
+        }
+        prefix = pixel as u16;
+    }
+
+    out.write(prefix, size);
+    out.write(end, size);
+    out.finish()
+}
+
+// GIF sizes are 16 bit and a frame needs at least one pixel
+fn gif_size(frame: &Frame) -> io::Result<(u16, u16)> {
+    match (u16::try_from(frame.width), u16::try_from(frame.height)) {
+        (Ok(width), Ok(height)) if width > 0 && height > 0 => Ok((width, height)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "a {}x{} frame does not fit in a GIF",
+                frame.width, frame.height
+            ),
+        )),
+    }
+}
+
+/// Writes the animation as a looping GIF, showing every frame for `delay`.
+///
+/// Fails with `InvalidInput` if a frame is empty or wider or taller than
+/// 65535 pixels.
+pub fn write_gif(out: &mut impl Write, animation: &Animation, delay: Duration) -> io::Result<()> {
+    let sizes = animation
+        .frames
+        .iter()
+        .map(gif_size)
+        .collect::<io::Result<Vec<_>>>()?;
+    let (width, height) = sizes.first().copied().unwrap_or((0, 0));
+
+    // the colour table size is a power of two, with at least two colours
+    let mut bits = 1;
+    while 1 << bits < animation.palette.len() {
+        bits += 1;
+    }
+
+    out.write_all(b"GIF89a")?;
+    out.write_all(&width.to_le_bytes())?;
+    out.write_all(&height.to_le_bytes())?;
+    out.write_all(&[0xf0 | (bits - 1) as u8, 0, 0])?;
+    for i in 0..1 << bits {
+        out.write_all(animation.palette.get(i).unwrap_or(&[0, 0, 0]))?;
+    }
+
+    // loop forever
+    out.write_all(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00")?;
+
+    let centis = (delay.as_millis() / 10) as u16;
+    for (frame, (width, height)) in animation.frames.iter().zip(sizes) {
+        out.write_all(&[0x21, 0xf9, 4, 0])?;
+        out.write_all(&centis.to_le_bytes())?;
+        out.write_all(&[0, 0])?;
+
+        out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        out.write_all(&[0])?;
+
+        // LZW needs at least two bits per code
+        let min_size = bits.max(2);
+        out.write_all(&[min_size as u8])?;
+        for block in gif_lzw(&frame.pixels, min_size).chunks(255) {
+            out.write_all(&[block.len() as u8])?;
+            out.write_all(block)?;
+        }
+        out.write_all(&[0])?;
+    }
+
+    out.write_all(&[0x3b])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // reads back the codes written by `gif_lzw`, growing the code size the
+    // way GIF decoders do
+    fn gif_unlzw(data: &[u8], min_size: u32) -> Vec<u8> {
+        let clear = 1 << min_size;
+        let end = clear + 1;
+
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let reset = |table: &mut Vec<Vec<u8>>| {
+            table.clear();
+            table.extend((0..clear).map(|i| vec![i as u8]));
+            table.push(Vec::new());
+            table.push(Vec::new());
+        };
+        reset(&mut table);
+
+        let mut size = min_size + 1;
+        let mut previous: Option<usize> = None;
+        let mut pixels = Vec::new();
+        let (mut buffer, mut bits, mut bytes) = (0u32, 0u32, data.iter());
+
+        loop {
+            while bits < size {
+                buffer |= (*bytes.next().expect("missing end code") as u32) << bits;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << size) - 1)) as usize;
+            buffer >>= size;
+            bits -= size;
+
+            if code == clear {
+                reset(&mut table);
+                size = min_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return pixels;
+            }
+
+            let entry = match previous {
+                None => table[code].clone(),
+                Some(previous) => {
+                    let entry = if code < table.len() {
+                        table[code].clone()
+                    } else {
+                        let mut entry = table[previous].clone();
+                        entry.push(table[previous][0]);
+                        entry
+                    };
+                    if table.len() < GIF_MAX_CODE as usize {
+                        let mut added = table[previous].clone();
+                        added.push(entry[0]);
+                        table.push(added);
+                        if table.len() == 1 << size && size < 12 {
+                            size += 1;
+                        }
+                    }
+                    entry
+                }
+            };
+            pixels.extend_from_slice(&entry);
+            previous = Some(code);
+        }
+    }
+
+    fn checkerboard() -> Frame {
+        let mut frame = Frame::new(3, 2);
+        frame.set(0, 0, 1);
+        frame.set(2, 0, 1);
+        frame.set(1, 1, 1);
+        frame
+    }
+
+    #[test]
+    fn test_scaled() {
+        let frame = checkerboard().scaled(2);
+
+        assert_eq!((frame.width(), frame.height()), (6, 4));
+        assert_eq!(frame.get(1, 1), 1);
+        assert_eq!(frame.get(2, 1), 0);
+        assert_eq!(frame.get(3, 3), 1);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut out = Vec::new();
+        write_ppm(&mut out, &checkerboard(), &[[0, 0, 0], [255, 0, 0]]).unwrap();
+
+        assert!(out.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(out.len(), 11 + 3 * 6);
+        assert_eq!(&out[11..17], &[255, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_png() {
+        let mut out = Vec::new();
+        write_png(&mut out, &checkerboard(), &[[0, 0, 0], [255, 0, 0]]).unwrap();
+
+        assert!(out.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x03\0\0\0\x02"));
+        assert!(out.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+
+        // two scanlines of a filter byte and three pixels, stored as is
+        let stored = zlib_stored(&[0, 1, 0, 1, 0, 0, 1, 0]);
+        assert_eq!(&stored[..7], &[0x78, 0x01, 1, 8, 0, 0xf7, 0xff]);
+        assert_eq!(&stored[7..15], &[0, 1, 0, 1, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_gif_lzw_round_trip() {
+        let short = vec![0, 1, 1, 1, 0, 0, 1, 2, 3, 3, 3, 3];
+        assert_eq!(gif_unlzw(&gif_lzw(&short, 2), 2), short);
+        assert_eq!(gif_unlzw(&gif_lzw(&[], 2), 2), Vec::<u8>::new());
+
+        // long and varied enough to fill the code table several times
+        let mut seed = 12345u32;
+        let long: Vec<u8> = (0..200_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                ((seed >> 16) % 7) as u8
+            })
+            .collect();
+        assert_eq!(gif_unlzw(&gif_lzw(&long, 3), 3), long);
+    }
+
+    #[test]
+    fn test_gif() {
+        let animation = Animation {
+            palette: vec![[0, 0, 0], [255, 255, 255], [255, 0, 0]],
+            frames: vec![checkerboard(), Frame::new(3, 2)],
+        };
+        let mut out = Vec::new();
+        write_gif(&mut out, &animation, Duration::from_millis(200)).unwrap();
+
+        assert!(out.starts_with(b"GIF89a\x03\0\x02\0\xf1\0\0"));
+        assert_eq!(&out[13..25], &[0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0]);
+        assert_eq!(out.iter().filter(|&&b| b == 0x2c).count(), 2);
+        assert_eq!(out.last(), Some(&0x3b));
+    }
+
+    #[test]
+    fn test_gif_size() {
+        for frame in [
+            Frame::new(70_000, 1),
+            Frame::new(1, 70_000),
+            Frame::new(0, 0),
+        ] {
+            let animation = Animation {
+                palette: vec![[0, 0, 0]],
+                frames: vec![checkerboard(), frame],
+            };
+            let err =
+                write_gif(&mut Vec::new(), &animation, Duration::from_millis(200)).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn test_simulate_seats() {
+        let layout = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+        let animation = simulate(11, &mut layout.as_bytes(), &Options::new()).unwrap();
+
+        // the starting layout and the five rounds where someone moves
+        assert_eq!(animation.frames.len(), 6);
+        assert!(animation.frames.iter().all(|f| f.width() == 10));
+
+        let mut options = Options::new();
+        options.set("generations", "2");
+        let animation = simulate(11, &mut layout.as_bytes(), &options).unwrap();
+        assert_eq!(animation.frames.len(), 3);
+
+        assert!(simulate(12, &mut layout.as_bytes(), &options).is_err());
+    }
+
+    #[test]
+    fn test_simulate_growing() {
+        let mut options = Options::new();
+        options.set("generations", "3");
+
+        let animation = simulate(17, &mut ".#.\n..#\n###".as_bytes(), &options).unwrap();
+        assert_eq!(animation.frames.len(), 4);
+        let size = (animation.frames[0].width(), animation.frames[0].height());
+        assert!(animation
+            .frames
+            .iter()
+            .all(|f| (f.width(), f.height()) == size));
+
+        let animation = simulate(24, &mut "esew\nnwwswee\nnwwswee".as_bytes(), &options).unwrap();
+        assert_eq!(animation.frames.len(), 4);
+    }
+}
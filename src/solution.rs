@@ -0,0 +1,208 @@
+use crate::input::InputError;
+use std::fmt::Display;
+use std::io::Read;
+use std::str::FromStr;
+
+pub trait Solution {
+    /// Solves the puzzle for the given input and returns the answer as printed
+    /// on the puzzle page.
+    fn solve(&self, reader: &mut dyn Read) -> Result<String, InputError>;
+
+    /// Solves the puzzle with extra settings given on the command line. Only
+    /// puzzles that list settings in `options` need to override this.
+    fn solve_with(&self, reader: &mut dyn Read, _options: &Options) -> Result<String, InputError> {
+        self.solve(reader)
+    }
+
+    /// Names of the settings accepted by `solve_with`.
+    fn options(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Whether the puzzle reads an input file at all with the given settings.
+    fn needs_input(&self, _options: &Options) -> bool {
+        true
+    }
+}
+
+/// Settings given as `--name value` on the command line.
+#[derive(Debug, Default, Clone)]
+pub struct Options {
+    values: Vec<(String, String)>,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.values.push((name.into(), value.into()));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.iter().any(|(n, _)| n == name)
+    }
+
+    /// The last value given for `name`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Every value given for `name`, in command line order.
+    pub fn get_all(&self, name: &str) -> Vec<&str> {
+        self.values
+            .iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    }
+
+    /// Parses the value of `name`, or returns `default` if it was not given.
+    pub fn parse_or<T>(&self, name: &str, default: T) -> Result<T, InputError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        match self.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|err| InputError::invalid(format!("--{} {:?}: {}", name, value, err))),
+            None => Ok(default),
+        }
+    }
+}
+
+pub struct Puzzle {
+    pub day: u32,
+    pub part: u32,
+    pub solution: &'static dyn Solution,
+}
+
+macro_rules! puzzle {
+    ($day:expr, $part:expr, $solution:path) => {
+        Puzzle {
+            day: $day,
+            part: $part,
+            solution: &$solution,
+        }
+    };
+}
+
+pub fn puzzles() -> Vec<Puzzle> {
+    use crate::*;
+
+    vec![
+        puzzle!(1, 1, day01::part1::Solver),
+        puzzle!(1, 2, day01::part2::Solver),
+        puzzle!(2, 1, day02::part1::Solver),
+        puzzle!(2, 2, day02::part2::Solver),
+        puzzle!(3, 1, day03::part1::Solver),
+        puzzle!(3, 2, day03::part2::Solver),
+        puzzle!(4, 1, day04::part1::Solver),
+        puzzle!(4, 2, day04::part2::Solver),
+        puzzle!(5, 1, day05::part1::Solver),
+        puzzle!(5, 2, day05::part2::Solver),
+        puzzle!(6, 1, day06::part1::Solver),
+        puzzle!(6, 2, day06::part2::Solver),
+        puzzle!(7, 1, day07::part1::Solver),
+        puzzle!(7, 2, day07::part2::Solver),
+        puzzle!(8, 1, day08::part1::Solver),
+        puzzle!(8, 2, day08::part2::Solver),
+        puzzle!(9, 1, day09::part1::Solver),
+        puzzle!(9, 2, day09::part2::Solver),
+        puzzle!(10, 1, day10::part1::Solver),
+        puzzle!(10, 2, day10::part2::Solver),
+        puzzle!(11, 1, day11::part1::Solver),
+        puzzle!(11, 2, day11::part2::Solver),
+        puzzle!(12, 1, day12::part1::Solver),
+        puzzle!(12, 2, day12::part2::Solver),
+        puzzle!(13, 1, day13::part1::Solver),
+        puzzle!(13, 2, day13::part2::Solver),
+        puzzle!(14, 1, day14::part1::Solver),
+        puzzle!(14, 2, day14::part2::Solver),
+        puzzle!(15, 1, day15::part1::SOLVER),
+        puzzle!(15, 2, day15::part2::SOLVER),
+        puzzle!(16, 1, day16::part1::Solver),
+        puzzle!(16, 2, day16::part2::Solver),
+        puzzle!(17, 1, day17::part1::Solver),
+        puzzle!(17, 2, day17::part2::Solver),
+        puzzle!(18, 1, day18::part1::Solver),
+        puzzle!(18, 2, day18::part2::Solver),
+        puzzle!(19, 1, day19::part1::Solver),
+        puzzle!(19, 2, day19::part2::Solver),
+        puzzle!(20, 1, day20::part1::Solver),
+        puzzle!(20, 2, day20::part2::Solver),
+        puzzle!(21, 1, day21::part1::Solver),
+        puzzle!(21, 2, day21::part2::Solver),
+        puzzle!(22, 1, day22::part1::Solver),
+        puzzle!(22, 2, day22::part2::Solver),
+        puzzle!(23, 1, day23::part1::Solver),
+        puzzle!(23, 2, day23::part2::Solver),
+        puzzle!(24, 1, day24::part1::Solver),
+        puzzle!(24, 2, day24::part2::Solver),
+        puzzle!(25, 1, day25::part1::Solver),
+    ]
+}
+
+pub fn find_puzzle(day: u32, part: u32) -> Option<Puzzle> {
+    puzzles()
+        .into_iter()
+        .find(|p| p.day == day && p.part == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_puzzle() {
+        let puzzle = find_puzzle(1, 1).unwrap();
+        let answer = puzzle
+            .solution
+            .solve(&mut "1721\n979\n366\n299\n675\n1456".as_bytes())
+            .unwrap();
+        assert_eq!(answer, "514579");
+
+        assert!(find_puzzle(20, 3).is_none());
+    }
+
+    #[test]
+    fn test_puzzles_are_unique() {
+        let puzzles = puzzles();
+        for (i, a) in puzzles.iter().enumerate() {
+            for b in puzzles.iter().skip(i + 1) {
+                assert!(a.day != b.day || a.part != b.part);
+            }
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let mut options = Options::new();
+        options.set("turns", "10");
+        options.set("rule", "8: 42");
+        options.set("rule", "11: 42 31");
+        options.set("turns", "20");
+
+        assert!(options.contains("rule"));
+        assert!(!options.contains("moves"));
+        assert_eq!(options.get("turns"), Some("20"));
+        assert_eq!(options.get_all("rule"), vec!["8: 42", "11: 42 31"]);
+        assert_eq!(options.parse_or("turns", 0).unwrap(), 20);
+        assert_eq!(options.parse_or("moves", 100).unwrap(), 100);
+        assert!(options.parse_or::<u32>("rule", 0).is_err());
+    }
+}